@@ -37,7 +37,7 @@ case "x$(uname)" in
 esac
 
 outfile=stats/day${daypad}vis-$outadd.txt
-\time $flags target/release/day${daypad}vis 2>&1 | tee "$outfile"
+\time $flags target/release/day${daypad}vis "${@:2}" 2>&1 | tee "$outfile"
 echo "------------------------------------------" >> "$outfile"
 echo $uname >> "$outfile"
 rustc -Vv >> "$outfile"
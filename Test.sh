@@ -12,4 +12,4 @@ fi
 
 daypad="$(printf %02d $1)"
 
-cargo test --bin day$daypad
+cargo test -p day$daypad
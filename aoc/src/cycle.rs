@@ -0,0 +1,207 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Cycle found in an iterated sequence x0, f(x0), f(f(x0)), ...
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of iterations before the cycle starts
+    pub prefix: usize,
+    /// Length of the cycle
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Returns the earliest iteration with the same state as iteration n
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Finds the cycle in an iterated sequence using Brent's algorithm.
+/// Only a constant number of states are kept, at the cost of calling `f` more often
+pub fn brent<T, F>(start: &T, mut f: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // Find the period by looking for a repeat within increasing powers of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = f(start);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = f(&hare);
+        period += 1;
+    }
+
+    // Find the start of the cycle with the hare a period ahead of the tortoise
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start.clone(), |x, _| f(&x));
+    let mut prefix = 0;
+
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// Finds the cycle in an iterated sequence using Floyd's algorithm.
+/// Only a constant number of states are kept, at the cost of calling `f` more often
+pub fn floyd<T, F>(start: &T, mut f: F) -> Cycle
+where
+    T: PartialEq + Clone,
+    F: FnMut(&T) -> T,
+{
+    // Find a state within the cycle with the hare moving twice as fast as the tortoise
+    let mut tortoise = f(start);
+    let mut hare = f(&tortoise);
+
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let next = f(&hare);
+        hare = f(&next);
+    }
+
+    // Find the start of the cycle
+    let mut tortoise = start.clone();
+    let mut prefix = 0;
+
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    // Find the period
+    let mut hare = f(&tortoise);
+    let mut period = 1;
+
+    while tortoise != hare {
+        hare = f(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// Calculates the state at iteration n of a sequence with a known cycle
+pub fn project<T, F>(start: &T, mut f: F, cycle: Cycle, n: usize) -> T
+where
+    T: Clone,
+    F: FnMut(&T) -> T,
+{
+    (0..cycle.equivalent(n)).fold(start.clone(), |x, _| f(&x))
+}
+
+/// States of an iterated sequence up to the first repeat, found by hashing each state
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    states: Vec<T>,
+    cycle: Cycle,
+}
+
+impl<T> History<T> {
+    /// Iterates a sequence, keeping each state, until a state repeats
+    pub fn find(start: T, mut f: impl FnMut(&T) -> T) -> Self
+    where
+        T: Hash + Eq + Clone,
+    {
+        let mut seen = HashMap::new();
+        let mut states = Vec::new();
+        let mut state = start;
+
+        loop {
+            match seen.entry(state.clone()) {
+                Entry::Occupied(e) => {
+                    let prefix = *e.get();
+                    let period = states.len() - prefix;
+
+                    return Self {
+                        states,
+                        cycle: Cycle { prefix, period },
+                    };
+                }
+                Entry::Vacant(e) => {
+                    e.insert(states.len());
+                }
+            }
+
+            let next = f(&state);
+            states.push(state);
+            state = next;
+        }
+    }
+
+    /// Returns the cycle found
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// Returns the state at iteration n
+    pub fn state_at(&self, n: usize) -> &T {
+        &self.states[self.cycle.equivalent(n)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_cycle() {
+        for start in 0..255 {
+            // Brute force
+            let mut seq = vec![start];
+
+            let (prefix, period) = loop {
+                let next = f(seq.last().unwrap());
+
+                if let Some(prefix) = seq.iter().position(|&x| x == next) {
+                    break (prefix, seq.len() - prefix);
+                }
+
+                seq.push(next);
+            };
+
+            let expected = Cycle { prefix, period };
+
+            assert_eq!(brent(&start, f), expected);
+            assert_eq!(floyd(&start, f), expected);
+
+            let history = History::find(start, f);
+            assert_eq!(history.cycle(), expected);
+
+            for n in [0, 1, 7, 100, 1000] {
+                let state = (0..n).fold(start, |x, _| f(&x));
+
+                assert_eq!(*history.state_at(n), state);
+                assert_eq!(project(&start, f, expected, n), state);
+            }
+
+            assert_eq!(
+                project(&start, f, expected, 1_000_000_000),
+                *history.state_at(1_000_000_000)
+            );
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::{error::Error, fs, path::Path};
+
+use toml::{Table, Value};
+
+use crate::{
+    input::{read_input_file, Input},
+    solution::Solution,
+};
+
+/// Extracts the contents of each `<pre><code>` block from puzzle page HTML.
+/// Tags inside the block (such as `<em>`) are removed and entities are decoded
+pub fn extract_code_blocks(html: &str) -> Vec<String> {
+    const START: &str = "<pre><code>";
+    const END: &str = "</code></pre>";
+
+    let mut blocks = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(START) {
+        rest = &rest[start + START.len()..];
+
+        let end = rest.find(END).unwrap_or(rest.len());
+
+        blocks.push(decode_entities(&strip_tags(&rest[..end])));
+
+        rest = &rest[end..];
+    }
+
+    blocks
+}
+
+/// Removes HTML tags from a string
+fn strip_tags(html: &str) -> String {
+    let mut result = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => (),
+        }
+    }
+
+    result
+}
+
+/// Decodes the HTML entities used in puzzle text
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').and_then(|semi| {
+            let c = match &rest[1..semi] {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                entity => match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                }
+                .and_then(char::from_u32),
+            }?;
+
+            Some((c, semi + 1))
+        });
+
+        match decoded {
+            Some((c, len)) => {
+                result.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+
+    result
+}
+
+/// Writes each `<pre><code>` block in a saved puzzle page to `exampleNN-M.txt` in a directory.
+/// Existing files are not overwritten. Returns the path of each file and whether it was written
+pub fn write_examples(
+    day: usize,
+    html: &str,
+    dir: &Path,
+) -> Result<Vec<(String, bool)>, Box<dyn Error>> {
+    extract_code_blocks(html)
+        .into_iter()
+        .enumerate()
+        .map(|(i, block)| {
+            let path = dir.join(format!("example{day:02}-{}.txt", i + 1));
+            let written = !path.exists();
+
+            if written {
+                fs::write(&path, block)?;
+            }
+
+            Ok((path.display().to_string(), written))
+        })
+        .collect()
+}
+
+/// Runs a solution against each example listed in `inputs/exampleNN.toml`, checking the
+/// answers. The file has a table for each example number with the expected `part1` and/or
+/// `part2` answers, for example:
+///
+/// ```toml
+/// [1]
+/// part1 = 142
+///
+/// [2]
+/// part2 = "abc"
+/// ```
+///
+/// Returns the number of answers checked. It is an error for the file to have no answers
+pub fn check_examples<S: Solution>() -> Result<usize, Box<dyn Error>> {
+    let file = format!("example{:02}.toml", S::DAY);
+
+    check_answers::<S>(&file, &read_input_file(&file)?)
+}
+
+/// Checks a solution against the expected answers parsed from the contents of a file
+fn check_answers<S: Solution>(file: &str, contents: &str) -> Result<usize, Box<dyn Error>> {
+    let expected = contents
+        .parse::<Table>()
+        .map_err(|e| format!("{file}: {e}"))?;
+
+    // Don't let an empty file pass
+    let answer_count = expected
+        .values()
+        .filter_map(Value::as_table)
+        .map(Table::len)
+        .sum::<usize>();
+
+    if answer_count == 0 {
+        Err(format!("{file}: No example answers found"))?
+    }
+
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for (example, answers) in &expected {
+        let number = example
+            .parse::<usize>()
+            .map_err(|_| format!("{file}: Invalid example number '{example}'"))?;
+
+        let answers = answers
+            .as_table()
+            .ok_or_else(|| format!("{file}: Example {example} is not a table"))?;
+
+        let input = Input::new_example(S::DAY, number)?;
+        let input = S::parse(&input)?;
+
+        for (part, expected) in answers {
+            let expected = match expected {
+                Value::Integer(i) => i.to_string(),
+                Value::String(s) => s.clone(),
+                _ => Err(format!(
+                    "{file}: Invalid answer for example {example} {part}"
+                ))?,
+            };
+
+            let answer = match part.as_str() {
+                "part1" => S::part1(&input).to_string(),
+                "part2" => S::part2(&input).to_string(),
+                _ => Err(format!(
+                    "{file}: Invalid part '{part}' for example {example}"
+                ))?,
+            };
+
+            if answer != expected {
+                failures.push(format!(
+                    "Example {example} {part}: got {answer}, expected {expected}"
+                ));
+            }
+
+            checked += 1;
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(checked)
+    } else {
+        Err(failures.join("\n").into())
+    }
+}
+
+/// Generates a test running a solution against the examples listed in `inputs/exampleNN.toml`.
+/// See [`check_examples`]
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples() {
+            if let Err(e) = $crate::examples::check_examples::<$solution>() {
+                panic!("{e}");
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract() {
+        let html = "\
+<p>For example:</p>
+<pre><code>1abc2
+<em>pqr</em>3stu8vwx
+</code></pre>
+<p>Then <code>x</code></p><pre><code>a -&gt; b &amp; c &lt;d&gt; &#39;e&#x27;
+</code></pre>";
+
+        assert_eq!(
+            extract_code_blocks(html),
+            vec![
+                "1abc2\npqr3stu8vwx\n".to_string(),
+                "a -> b & c <d> 'e'\n".to_string()
+            ]
+        );
+
+        assert_eq!(decode_entities("a & b &bogus; &"), "a & b &bogus; &");
+    }
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        const DAY: usize = 0;
+
+        type Input = ();
+
+        fn parse(_input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> impl std::fmt::Display {
+            0
+        }
+
+        fn part2(_input: &Self::Input) -> impl std::fmt::Display {
+            0
+        }
+    }
+
+    #[test]
+    fn test_no_answers() {
+        for contents in ["", "# [1]\n# part1 = 142\n", "[1]\n\n[2]\n"] {
+            assert_eq!(
+                check_answers::<Dummy>("example00.toml", contents)
+                    .unwrap_err()
+                    .to_string(),
+                "example00.toml: No example answers found"
+            );
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Orthogonal direction. North is towards y = 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    /// All directions, clockwise from north
+    pub const ALL: [Dir4; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    /// Returns the direction after turning 90 degrees anticlockwise
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir4::N => Dir4::W,
+            Dir4::E => Dir4::N,
+            Dir4::S => Dir4::E,
+            Dir4::W => Dir4::S,
+        }
+    }
+
+    /// Returns the direction after turning 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir4::N => Dir4::E,
+            Dir4::E => Dir4::S,
+            Dir4::S => Dir4::W,
+            Dir4::W => Dir4::N,
+        }
+    }
+
+    /// Returns the opposite direction
+    pub fn reverse(self) -> Self {
+        match self {
+            Dir4::N => Dir4::S,
+            Dir4::E => Dir4::W,
+            Dir4::S => Dir4::N,
+            Dir4::W => Dir4::E,
+        }
+    }
+
+    /// Returns true for east and west
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::E | Dir4::W)
+    }
+
+    /// Returns true for north and south
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::N | Dir4::S)
+    }
+
+    /// Returns the unit vector for the direction
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir4::N => Vec2::new(0, -1),
+            Dir4::E => Vec2::new(1, 0),
+            Dir4::S => Vec2::new(0, 1),
+            Dir4::W => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = String;
+
+    /// Converts from U/D/L/R, N/S/E/W or ^/v/</> characters
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Dir4::N),
+            'R' | 'E' | '>' => Ok(Dir4::E),
+            'D' | 'S' | 'v' => Ok(Dir4::S),
+            'L' | 'W' | '<' => Ok(Dir4::W),
+            _ => Err(format!("Invalid direction '{c}'")),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(format!("Invalid direction '{s}'")),
+        }
+    }
+}
+
+impl Display for Dir4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Dir4::N => 'N',
+            Dir4::E => 'E',
+            Dir4::S => 'S',
+            Dir4::W => 'W',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+/// Orthogonal or diagonal direction. North is towards y = 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All directions, clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// Returns the direction after turning 45 degrees anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Returns the direction after turning 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Returns the opposite direction
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns the offset for the direction
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Dir8::N => Vec2::new(0, -1),
+            Dir8::NE => Vec2::new(1, -1),
+            Dir8::E => Vec2::new(1, 0),
+            Dir8::SE => Vec2::new(1, 1),
+            Dir8::S => Vec2::new(0, 1),
+            Dir8::SW => Vec2::new(-1, 1),
+            Dir8::W => Vec2::new(-1, 0),
+            Dir8::NW => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::N => Dir8::N,
+            Dir4::E => Dir8::E,
+            Dir4::S => Dir8::S,
+            Dir4::W => Dir8::W,
+        }
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = String;
+
+    /// Converts from N/NE/E/SE/S/SW/W/NW, or the single character forms accepted by `Dir4`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::NE),
+            "SE" => Ok(Dir8::SE),
+            "SW" => Ok(Dir8::SW),
+            "NW" => Ok(Dir8::NW),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+/// Signed 2D vector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Vec2 {
+    /// Creates a new vector
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the Manhattan length of the vector
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Adds two vectors, returning None on overflow
+    pub fn checked_add(self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// Subtracts two vectors, returning None on overflow
+    pub fn checked_sub(self, other: Vec2) -> Option<Vec2> {
+        Some(Vec2::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    /// Multiplies the vector by a scalar, returning None on overflow
+    pub fn checked_mul(self, n: i64) -> Option<Vec2> {
+        Some(Vec2::new(self.x.checked_mul(n)?, self.y.checked_mul(n)?))
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, n: i64) -> Vec2 {
+        Vec2::new(self.x * n, self.y * n)
+    }
+}
+
+/// Unsigned 2D position, as used to index grids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    /// Creates a new position
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Adds a vector to the position, returning None if either coordinate would be out of range
+    pub fn checked_add(self, v: Vec2) -> Option<Pos> {
+        Some(Pos::new(
+            self.x.checked_add_signed(v.x.try_into().ok()?)?,
+            self.y.checked_add_signed(v.y.try_into().ok()?)?,
+        ))
+    }
+
+    /// Moves one step in a direction, returning None if either coordinate would be negative
+    pub fn step(self, dir: Dir4) -> Option<Pos> {
+        self.checked_add(dir.offset())
+    }
+
+    /// Moves one step in a direction, returning None if the new position is outside
+    /// the bounds (0, 0) to (width - 1, height - 1)
+    pub fn step_within(self, dir: Dir4, width: usize, height: usize) -> Option<Pos> {
+        self.step(dir).filter(|p| p.x < width && p.y < height)
+    }
+
+    /// Returns the vector from another position to this one
+    pub fn diff(self, other: Pos) -> Vec2 {
+        Vec2::new(
+            self.x as i64 - other.x as i64,
+            self.y as i64 - other.y as i64,
+        )
+    }
+
+    /// Returns the Manhattan distance between two positions
+    pub fn manhattan(self, other: Pos) -> u64 {
+        (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as u64
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Self {
+        Pos::new(x, y)
+    }
+}
+
+impl From<Pos> for (usize, usize) {
+    fn from(pos: Pos) -> Self {
+        (pos.x, pos.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.offset() + dir.reverse().offset(), Vec2::default());
+            assert_eq!(Dir8::from(dir).reverse(), Dir8::from(dir.reverse()));
+        }
+
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::NW.turn_right(), Dir8::N);
+        assert_eq!(Dir8::NE.reverse(), Dir8::SW);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("U".parse::<Dir4>(), Ok(Dir4::N));
+        assert_eq!("W".parse::<Dir4>(), Ok(Dir4::W));
+        assert_eq!(Dir4::try_from('v'), Ok(Dir4::S));
+        assert_eq!(Dir4::try_from('>'), Ok(Dir4::E));
+        assert!("X".parse::<Dir4>().is_err());
+        assert!("UD".parse::<Dir4>().is_err());
+        assert_eq!("SE".parse::<Dir8>(), Ok(Dir8::SE));
+        assert_eq!("L".parse::<Dir8>(), Ok(Dir8::W));
+    }
+
+    #[test]
+    fn test_pos() {
+        let pos = Pos::new(0, 2);
+
+        assert_eq!(pos.step(Dir4::W), None);
+        assert_eq!(pos.step(Dir4::N), Some(Pos::new(0, 1)));
+        assert_eq!(pos.step_within(Dir4::S, 3, 3), None);
+        assert_eq!(pos.checked_add(Vec2::new(3, -2)), Some(Pos::new(3, 0)));
+        assert_eq!(pos.checked_add(Vec2::new(0, i64::MIN)), None);
+        assert_eq!(pos.manhattan(Pos::new(3, 0)), 5);
+        assert_eq!(Pos::new(3, 0).diff(pos), Vec2::new(3, -2));
+        assert_eq!(Vec2::new(i64::MAX, 0).checked_add(Vec2::new(1, 0)), None);
+        assert_eq!(Vec2::new(-3, 4).manhattan(), 7);
+    }
+}
@@ -0,0 +1,401 @@
+use std::ops::RangeInclusive;
+
+use super::font::{glyph, ADVANCE, GLYPH_WIDTH};
+
+/// Colour of a canvas pixel
+pub trait Pixel: Copy + PartialEq {}
+
+/// Palette index
+impl Pixel for u8 {}
+
+/// RGB colour
+impl Pixel for [u8; 3] {}
+
+/// Frame of pixels with drawing primitives, palette indexes by default. Drawing outside the
+/// canvas is clipped
+#[derive(Debug, PartialEq, Eq)]
+pub struct Canvas<P = u8> {
+    width: usize,
+    height: usize,
+    cell_width: usize,
+    cell_height: usize,
+    pixels: Vec<P>,
+}
+
+impl<P: Clone> Clone for Canvas<P> {
+    fn clone(&self) -> Self {
+        Self {
+            width: self.width,
+            height: self.height,
+            cell_width: self.cell_width,
+            cell_height: self.cell_height,
+            pixels: self.pixels.clone(),
+        }
+    }
+
+    /// Reuses the pixel buffer, so resetting a scratch canvas from a base frame doesn't allocate
+    fn clone_from(&mut self, source: &Self) {
+        self.width = source.width;
+        self.height = source.height;
+        self.cell_width = source.cell_width;
+        self.cell_height = source.cell_height;
+        self.pixels.clone_from(&source.pixels);
+    }
+}
+
+/// Frame of RGB colours
+pub type RgbCanvas = Canvas<[u8; 3]>;
+
+impl Canvas {
+    /// Creates a new canvas filled with colour 0
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, 0)
+    }
+}
+
+impl<P: Pixel> Canvas<P> {
+    /// Creates a new canvas filled with a colour
+    pub fn filled(width: usize, height: usize, colour: P) -> Self {
+        Self {
+            width,
+            height,
+            cell_width: 1,
+            cell_height: 1,
+            pixels: vec![colour; width * height],
+        }
+    }
+
+    /// Sets the size of each cell for cell grid addressing
+    pub fn with_cell_size(mut self, cell_width: usize, cell_height: usize) -> Self {
+        self.cell_width = cell_width;
+        self.cell_height = cell_height;
+        self
+    }
+
+    /// Returns the width of the canvas in pixels
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the canvas in pixels
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns all of the pixels, row by row
+    #[inline]
+    pub fn pixels(&self) -> &[P] {
+        &self.pixels
+    }
+
+    /// Returns a row of pixels
+    #[inline]
+    pub fn row(&self, y: usize) -> &[P] {
+        &self.pixels[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns a mutable row of pixels
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> &mut [P] {
+        &mut self.pixels[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the colour of a pixel
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> P {
+        assert!(x < self.width && y < self.height);
+        self.pixels[y * self.width + x]
+    }
+
+    /// Sets the colour of a pixel
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, colour: P) {
+        assert!(x < self.width && y < self.height);
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    /// Copies a range of rows from another canvas of the same size
+    pub fn copy_rows_from(&mut self, other: &Canvas<P>, rows: RangeInclusive<usize>) {
+        assert!(self.width == other.width && self.height == other.height);
+
+        let range = rows.start() * self.width..(rows.end() + 1) * self.width;
+
+        self.pixels[range.clone()].copy_from_slice(&other.pixels[range]);
+    }
+
+    /// Sets the colour of a pixel if it is on the canvas
+    #[inline]
+    fn plot(&mut self, x: isize, y: isize, colour: P) {
+        if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            self.pixels[y as usize * self.width + x as usize] = colour;
+        }
+    }
+
+    /// Fills the whole canvas with a colour
+    pub fn clear(&mut self, colour: P) {
+        self.pixels.fill(colour);
+    }
+
+    /// Fills a rectangle with a colour
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, colour: P) {
+        let right = x.saturating_add(width).min(self.width);
+        let bottom = y.saturating_add(height).min(self.height);
+
+        if x >= right {
+            return;
+        }
+
+        for row in y..bottom {
+            self.pixels[row * self.width + x..row * self.width + right].fill(colour);
+        }
+    }
+
+    /// Draws a line between two points inclusive using Bresenham's algorithm
+    pub fn line(&mut self, x0: isize, y0: isize, x1: isize, y1: isize, colour: P) {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+
+        let (mut x, mut y) = (x0, y0);
+        let mut err = dx + dy;
+
+        loop {
+            self.plot(x, y, colour);
+
+            if x == x1 && y == y1 {
+                break;
+            }
+
+            let e2 = 2 * err;
+
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    /// Copies a sprite of colours to the canvas with its top left at (x, y).
+    /// Pixels in the transparent colour are skipped
+    pub fn blit<R: AsRef<[P]>>(
+        &mut self,
+        x: usize,
+        y: usize,
+        sprite: &[R],
+        transparent: Option<P>,
+    ) {
+        for (sy, row) in sprite.iter().enumerate() {
+            for (sx, &colour) in row.as_ref().iter().enumerate() {
+                if Some(colour) != transparent {
+                    self.plot((x + sx) as isize, (y + sy) as isize, colour);
+                }
+            }
+        }
+    }
+
+    /// Draws a colour at each non-zero pixel of a bitmap with its top left at (x, y)
+    pub fn blit_mask<R: AsRef<[u8]>>(&mut self, x: usize, y: usize, mask: &[R], colour: P) {
+        for (sy, row) in mask.iter().enumerate() {
+            for (sx, &bit) in row.as_ref().iter().enumerate() {
+                if bit != 0 {
+                    self.plot((x + sx) as isize, (y + sy) as isize, colour);
+                }
+            }
+        }
+    }
+
+    /// Copies another canvas on to this one with its top left at (x, y).
+    /// Pixels in the transparent colour are skipped
+    pub fn blit_canvas(&mut self, x: usize, y: usize, sprite: &Canvas<P>, transparent: Option<P>) {
+        for sy in 0..sprite.height {
+            self.blit(x, y + sy, &[sprite.row(sy)], transparent);
+        }
+    }
+
+    /// Returns the pixel position of the top left of a cell
+    #[inline]
+    pub fn cell(&self, cx: usize, cy: usize) -> (usize, usize) {
+        (cx * self.cell_width, cy * self.cell_height)
+    }
+
+    /// Returns the number of whole cells across and down the canvas
+    pub fn cells(&self) -> (usize, usize) {
+        (self.width / self.cell_width, self.height / self.cell_height)
+    }
+
+    /// Fills a cell with a colour
+    pub fn fill_cell(&mut self, cx: usize, cy: usize, colour: P) {
+        let (x, y) = self.cell(cx, cy);
+
+        self.fill_rect(x, y, self.cell_width, self.cell_height, colour);
+    }
+
+    /// Copies a sprite of colours to a cell. See [`Canvas::blit`]
+    pub fn blit_cell<R: AsRef<[P]>>(
+        &mut self,
+        cx: usize,
+        cy: usize,
+        sprite: &[R],
+        transparent: Option<P>,
+    ) {
+        let (x, y) = self.cell(cx, cy);
+
+        self.blit(x, y, sprite, transparent);
+    }
+
+    /// Draws a colour at each non-zero pixel of a bitmap in a cell. See [`Canvas::blit_mask`]
+    pub fn blit_mask_cell<R: AsRef<[u8]>>(&mut self, cx: usize, cy: usize, mask: &[R], colour: P) {
+        let (x, y) = self.cell(cx, cy);
+
+        self.blit_mask(x, y, mask, colour);
+    }
+
+    /// Draws a line of text in the built in font with its top left at (x, y)
+    pub fn text(&mut self, x: usize, y: usize, text: &str, colour: P) {
+        for (i, c) in text.chars().enumerate() {
+            let mask = glyph(c).map(|bits| {
+                let mut row = [0; GLYPH_WIDTH];
+
+                for (bit, pixel) in row.iter_mut().enumerate() {
+                    *pixel = (bits >> (GLYPH_WIDTH - 1 - bit)) & 1;
+                }
+
+                row
+            });
+
+            self.blit_mask(x + i * ADVANCE, y, &mask, colour);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gif::font::{text_width, GLYPH_HEIGHT};
+
+    /// Draws a canvas as characters, '.' for colour 0 and the colour digit otherwise
+    fn render(canvas: &Canvas) -> String {
+        (0..canvas.height())
+            .map(|y| {
+                canvas
+                    .row(y)
+                    .iter()
+                    .map(|&c| if c == 0 { '.' } else { (b'0' + c) as char })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rect() {
+        let mut canvas = Canvas::new(5, 4);
+
+        canvas.fill_rect(1, 1, 2, 2, 1);
+        canvas.fill_rect(4, 3, 10, 10, 2);
+        canvas.fill_rect(9, 0, 1, 1, 3);
+        canvas.set(0, 3, 4);
+
+        assert_eq!(render(&canvas), ".....\n.11..\n.11..\n4...2\n");
+        assert_eq!(canvas.get(1, 2), 1);
+
+        canvas.clear(5);
+        assert!(canvas.pixels().iter().all(|&p| p == 5));
+    }
+
+    #[test]
+    fn test_line() {
+        let mut canvas = Canvas::new(5, 3);
+
+        canvas.line(0, 0, 4, 2, 1);
+        canvas.line(4, 0, 4, -5, 2);
+
+        assert_eq!(render(&canvas), "1...2\n.11..\n...11\n");
+
+        // Single point
+        let mut point = Canvas::new(2, 2);
+        point.line(1, 1, 1, 1, 3);
+
+        assert_eq!(render(&point), "..\n.3\n");
+    }
+
+    #[test]
+    fn test_blit() {
+        let mut canvas = Canvas::new(6, 4).with_cell_size(3, 2);
+
+        assert_eq!(canvas.cells(), (2, 2));
+        assert_eq!(canvas.cell(1, 1), (3, 2));
+
+        canvas.fill_cell(0, 0, 1);
+        canvas.blit_cell(1, 0, &[[0, 2, 0], [2, 2, 2]], Some(0));
+        canvas.blit_mask_cell(0, 1, &[[1, 0, 1], [0, 1, 0]], 3);
+        canvas.blit(5, 3, &[[4, 4], [4, 4]], None);
+
+        assert_eq!(render(&canvas), "111.2.\n111222\n3.3...\n.3...4\n");
+
+        let mut sprite = Canvas::new(2, 2);
+        sprite.set(1, 0, 5);
+
+        canvas.blit_canvas(0, 0, &sprite, Some(0));
+        assert_eq!(render(&canvas), "151.2.\n111222\n3.3...\n.3...4\n");
+
+        canvas.blit_canvas(0, 0, &sprite, None);
+        assert_eq!(render(&canvas), ".51.2.\n..1222\n3.3...\n.3...4\n");
+    }
+
+    #[test]
+    fn test_text() {
+        let mut canvas = Canvas::new(7, GLYPH_HEIGHT);
+
+        canvas.text(0, 0, "a1", 1);
+
+        assert_eq!(
+            render(&canvas),
+            ".1...1.\n1.1.11.\n111..1.\n1.1..1.\n1.1.111\n"
+        );
+        assert_eq!(text_width("a1"), 7);
+    }
+
+    #[test]
+    fn test_clone_from() {
+        let mut base = Canvas::new(4, 3).with_cell_size(2, 2);
+        base.fill_rect(1, 1, 2, 1, 5);
+
+        let mut scratch = base.clone();
+        scratch.clear(7);
+
+        let pixels = scratch.pixels().as_ptr();
+        scratch.clone_from(&base);
+
+        assert_eq!(scratch, base);
+        assert_eq!(scratch.pixels().as_ptr(), pixels);
+    }
+
+    #[test]
+    fn test_rgb() {
+        let mut canvas = RgbCanvas::filled(3, 2, [1, 2, 3]);
+
+        canvas.fill_rect(1, 0, 5, 1, [255, 0, 0]);
+        canvas.blit_mask(0, 1, &[[1, 0, 1]], [0, 255, 0]);
+
+        assert_eq!(
+            canvas.pixels(),
+            &[
+                [1, 2, 3],
+                [255, 0, 0],
+                [255, 0, 0],
+                [0, 255, 0],
+                [1, 2, 3],
+                [0, 255, 0]
+            ]
+        );
+    }
+}
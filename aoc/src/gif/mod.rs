@@ -1,10 +1,34 @@
-use std::{borrow::Cow, cmp::max, error::Error, fs::File};
+use std::{borrow::Cow, cmp::max, collections::HashMap, error::Error, fs::File};
 
-use gif::{Encoder, Frame, Repeat};
+use gif::{DisposalMethod, Encoder, Frame, Repeat};
 
-use self::region::Region;
+pub use self::{
+    canvas::{Canvas, Pixel, RgbCanvas},
+    palette::{Palette, PaletteError, Ramp, MAX_COLOURS},
+    png::{Apng, PngDir, FRAME_LIST},
+    region::Region,
+    sink::{Format, FrameSink},
+};
 
+mod canvas;
+pub mod font;
+mod palette;
+mod png;
 mod region;
+mod sink;
+
+/// Size of the square tiles used to group changed pixels into separate regions
+const TILE_SIZE: usize = 16;
+
+/// Regions are merged if encoding them together covers at most this many more output pixels
+/// than encoding them separately, roughly the cost of an extra frame header
+const MERGE_PIXELS: usize = 256;
+
+/// Maximum number of separate changed regions before falling back to a single region
+const MAX_REGIONS: usize = 64;
+
+/// Viewers treat frame delays below this as slow default delays, so each sub-frame gets at least this
+const MIN_DELAY: u16 = 2;
 
 pub struct Gif {
     width: u16,
@@ -14,7 +38,20 @@ pub struct Gif {
     gif_width: u16,
     gif_height: u16,
     encoder: Encoder<File>,
-    last_frame: Option<Vec<Vec<u8>>>,
+    /// Copy of the last frame output
+    last_frame: Option<Canvas>,
+    /// Area which may have changed since the last frame, if hinted by the caller
+    dirty: Option<Region>,
+    /// Scaled pixels for the frame being encoded
+    out_buffer: Vec<u8>,
+    /// Palette index used for unchanged pixels in delta frames
+    transparent: Option<u8>,
+    /// Palette index of each colour, for converting RGB frames
+    colour_indexes: HashMap<[u8; 3], u8>,
+    /// Reused frame for converting RGB frames
+    converted: Option<Canvas>,
+    /// Check each frame hasn't changed outside the dirty hints
+    check_hints: bool,
 }
 
 impl Gif {
@@ -39,6 +76,13 @@ impl Gif {
         // Ininitely repeat
         encoder.set_repeat(Repeat::Infinite)?;
 
+        // Map colours to the first palette entry with the colour
+        let mut colour_indexes = HashMap::new();
+
+        for (index, colour) in palette.iter().enumerate() {
+            colour_indexes.entry(*colour).or_insert(index as u8);
+        }
+
         Ok(Self {
             width,
             height,
@@ -48,85 +92,237 @@ impl Gif {
             gif_height,
             encoder,
             last_frame: None,
+            dirty: None,
+            out_buffer: Vec::with_capacity(gif_width as usize * gif_height as usize),
+            transparent: None,
+            colour_indexes,
+            converted: None,
+            check_hints: false,
         })
     }
 
+    /// Encodes pixels which haven't changed since the last frame with a transparent palette index,
+    /// which shrinks frames where only scattered pixels change. The index should be a spare palette
+    /// entry - regions containing changed pixels drawn in it are encoded without transparency
+    pub fn with_transparent_deltas(mut self, index: u8) -> Self {
+        self.transparent = Some(index);
+        self
+    }
+
+    /// Checks that each frame hasn't changed outside the area hinted with `mark_dirty`, panicking
+    /// if it has. This scans every frame in full, so is only for debugging hints
+    pub fn with_hint_checks(mut self) -> Self {
+        self.check_hints = true;
+        self
+    }
+
     /// Output a frame to the GIF
-    pub fn draw_frame(
-        &mut self,
-        frame_data: Vec<Vec<u8>>,
-        delay: u16,
-    ) -> Result<(), Box<dyn Error>> {
+    pub fn draw_frame(&mut self, frame_data: &Canvas, delay: u16) -> Result<(), Box<dyn Error>> {
         self.draw_frame_identical_check(frame_data, delay, IdenticalAction::Ignore)
     }
 
     /// Output a frame to the GIF and takes a given action if the frame is identical to the last
     pub fn draw_frame_identical_check(
         &mut self,
-        frame_data: Vec<Vec<u8>>,
+        frame_data: &Canvas,
         delay: u16,
         identical_action: IdenticalAction,
     ) -> Result<(), Box<dyn Error>> {
         // Make sure the frame looks like the correct size
-        assert_eq!(frame_data.len(), self.height as usize);
-        assert_eq!(frame_data[0].len(), self.width as usize);
-
-        // Calculate the difference between this frame and the last
-        match self.frame_difference(&frame_data) {
-            None => {
-                // No difference
-                match identical_action {
-                    IdenticalAction::Delay => self.delay(delay)?,
-                    IdenticalAction::Ignore => (),
+        assert_eq!(frame_data.height(), self.height as usize);
+        assert_eq!(frame_data.width(), self.width as usize);
+
+        // Calculate the regions which differ between this frame and the last
+        let mut regions = self.frame_difference(frame_data);
+
+        self.dirty = None;
+
+        if regions.is_empty() {
+            // No difference
+            match identical_action {
+                IdenticalAction::Delay => self.delay(delay)?,
+                IdenticalAction::Ignore => (),
+            }
+
+            return Ok(());
+        }
+
+        // Each region is written as a sub-frame, all but the last with the minimum delay
+        let delay = max(MIN_DELAY, delay);
+        limit_regions(&mut regions, (delay / MIN_DELAY) as usize);
+
+        let last_sub_frame = regions.len() - 1;
+
+        for (i, region) in regions.iter().enumerate() {
+            let delay = if i == last_sub_frame {
+                delay - MIN_DELAY * last_sub_frame as u16
+            } else {
+                MIN_DELAY
+            };
+
+            self.write_region(frame_data, region, delay)?;
+        }
+
+        // Save the changed sections of the frame
+        match &mut self.last_frame {
+            Some(last_frame) => {
+                for region in &regions {
+                    last_frame.copy_rows_from(frame_data, region.y_range());
                 }
             }
-            Some(difference) => {
-                // Scale the frame up
-                let out_section = frame_data
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(y, l)| {
-                        if difference.contains_y(y as u16) {
-                            Some(&l[difference.x_range()])
-                        } else {
-                            None
-                        }
-                    })
-                    .fold(
-                        Vec::with_capacity(self.gif_height as usize * self.gif_width as usize),
-                        |mut acc: Vec<u8>, line| {
-                            let expanded_line: Vec<u8> = line
-                                .iter()
-                                .flat_map(|pix| vec![*pix; self.x_scale as usize])
-                                .collect();
-
-                            for _ in 0..self.y_scale {
-                                acc.extend(&expanded_line);
-                            }
-
-                            acc
-                        },
-                    );
-
-                // Create the next frame
-                let frame = Frame {
-                    top: difference.top() * self.y_scale,
-                    left: difference.left() * self.x_scale,
-                    width: difference.width() * self.x_scale,
-                    height: difference.height() * self.y_scale,
-                    buffer: Cow::Borrowed(&*out_section),
-                    delay: max(2, delay),
-                    ..Default::default()
+            None => self.last_frame = Some(frame_data.clone()),
+        }
+
+        Ok(())
+    }
+
+    /// Hints that only pixels in the given rectangle of the frame may have changed before the
+    /// next frame is drawn. Hints accumulate until the next frame, and limit the search for
+    /// changed pixels to the hinted area. Without any hints the whole frame is searched
+    pub fn mark_dirty(&mut self, x: usize, y: usize, width: usize, height: usize) {
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let right = (x + width - 1).min(self.width as usize - 1);
+        let bottom = (y + height - 1).min(self.height as usize - 1);
+
+        if x > right || y > bottom {
+            return;
+        }
+
+        let region = Region::new(y as u16, x as u16, bottom as u16, right as u16);
+
+        match &mut self.dirty {
+            Some(dirty) => dirty.union(&region),
+            None => self.dirty = Some(region),
+        }
+    }
+
+    /// Outputs a frame of RGB colours. Every colour must be in the palette
+    pub fn draw_rgb_frame(
+        &mut self,
+        frame_data: &RgbCanvas,
+        delay: u16,
+    ) -> Result<(), Box<dyn Error>> {
+        // Make sure the frame looks like the correct size
+        assert_eq!(frame_data.height(), self.height as usize);
+        assert_eq!(frame_data.width(), self.width as usize);
+
+        // Only the hinted area needs converting if the last frame was converted
+        let full = Region::new(0, 0, self.height - 1, self.width - 1);
+
+        let (mut frame, convert) = match (self.converted.take(), &self.dirty) {
+            (Some(frame), Some(dirty)) => (frame, dirty.clone()),
+            (Some(frame), None) => (frame, full),
+            (None, _) => (Canvas::new(frame_data.width(), frame_data.height()), full),
+        };
+
+        // Most pixels are the same colour as the one before
+        let mut last = None;
+
+        for y in convert.y_range() {
+            let row = &mut frame.row_mut(y)[convert.x_range()];
+
+            for (pix, colour) in row.iter_mut().zip(&frame_data.row(y)[convert.x_range()]) {
+                *pix = match last {
+                    Some((last_colour, index)) if last_colour == colour => index,
+                    _ => {
+                        let Some(&index) = self.colour_indexes.get(colour) else {
+                            return Err(
+                                format!("Colour {colour:?} is not in the GIF palette").into()
+                            );
+                        };
+
+                        last = Some((colour, index));
+                        index
+                    }
                 };
+            }
+        }
+
+        let result = self.draw_frame(&frame, delay);
+        self.converted = Some(frame);
+
+        result
+    }
+
+    /// Scales a region of a frame up and writes it out as a GIF frame
+    fn write_region(
+        &mut self,
+        frame_data: &Canvas,
+        region: &Region,
+        delay: u16,
+    ) -> Result<(), Box<dyn Error>> {
+        // Only use transparency for unchanged pixels if no changed pixel needs the index
+        let transparent = match (&self.last_frame, self.transparent) {
+            (Some(last_frame), Some(index)) => {
+                let clash = region.y_range().any(|y| {
+                    let l1 = &last_frame.row(y)[region.x_range()];
+                    let l2 = &frame_data.row(y)[region.x_range()];
 
-                // Write out the frame
-                self.encoder.write_frame(&frame)?;
+                    l1.iter().zip(l2).any(|(p1, p2)| p1 != p2 && *p2 == index)
+                });
 
-                // Save the last frame
-                self.last_frame = Some(frame_data);
+                if clash {
+                    None
+                } else {
+                    Some((last_frame, index))
+                }
+            }
+            _ => None,
+        };
+
+        // Scale the changed section up
+        self.out_buffer.clear();
+
+        for y in region.y_range() {
+            let line = &frame_data.row(y)[region.x_range()];
+            let start = self.out_buffer.len();
+
+            match transparent {
+                Some((last_frame, index)) => {
+                    let last_line = &last_frame.row(y)[region.x_range()];
+
+                    for (pix, last_pix) in line.iter().zip(last_line) {
+                        let pix = if pix == last_pix { index } else { *pix };
+
+                        self.out_buffer
+                            .extend(std::iter::repeat_n(pix, self.x_scale as usize));
+                    }
+                }
+                None => {
+                    for pix in line {
+                        self.out_buffer
+                            .extend(std::iter::repeat_n(*pix, self.x_scale as usize));
+                    }
+                }
+            }
+
+            let end = self.out_buffer.len();
+
+            for _ in 1..self.y_scale {
+                self.out_buffer.extend_from_within(start..end);
             }
         }
 
+        // Create the next frame
+        let frame = Frame {
+            top: region.top() * self.y_scale,
+            left: region.left() * self.x_scale,
+            width: region.width() * self.x_scale,
+            height: region.height() * self.y_scale,
+            buffer: Cow::Borrowed(&self.out_buffer),
+            delay,
+            transparent: transparent.map(|(_, index)| index),
+            // Leave previous frames in place for transparent pixels to show through
+            dispose: DisposalMethod::Keep,
+            ..Default::default()
+        };
+
+        // Write out the frame
+        self.encoder.write_frame(&frame)?;
+
         Ok(())
     }
 
@@ -159,42 +355,208 @@ impl Gif {
 
     /// Returns a new empty fram for the image
     #[inline]
-    pub fn empty_frame(&self) -> Vec<Vec<u8>> {
+    pub fn empty_frame(&self) -> Canvas {
         let (w, h) = self.dimensions();
 
-        vec![vec![0; w as usize]; h as usize]
+        Canvas::new(w as usize, h as usize)
     }
 
-    /// Calculates the difference between a given frame and the last frame output
-    /// Returns None if there is no difference
+    /// Calculates the regions which differ between a given frame and the last frame output
+    /// Returns an empty list if there is no difference
     /// If there is no previous frame then the whole frame is considered changed
-    fn frame_difference(&self, frame_data: &[Vec<u8>]) -> Option<Region> {
-        if let Some(last_frame) = &self.last_frame {
-            let mut region = Region::max_init();
-
-            // Process each row
-            for (y, (l1, l2)) in last_frame.iter().zip(frame_data.iter()).enumerate() {
-                // Process each pixel
-                for (x, (_, _)) in l1
-                    .iter()
-                    .zip(l2.iter())
-                    .enumerate()
-                    .filter(|(_, (p1, p2))| *p1 != *p2)
-                {
-                    region.max_add(x as u16, y as u16)
+    fn frame_difference(&self, frame_data: &Canvas) -> Vec<Region> {
+        let Some(last_frame) = &self.last_frame else {
+            // No previous frame
+            return vec![Region::new(0, 0, self.height - 1, self.width - 1)];
+        };
+
+        // Only search the hinted area if given
+        let search = match &self.dirty {
+            Some(dirty) => dirty.clone(),
+            None => Region::new(0, 0, self.height - 1, self.width - 1),
+        };
+
+        let merge_pixels = MERGE_PIXELS / (self.x_scale as usize * self.y_scale as usize);
+
+        let regions = changed_regions(last_frame, frame_data, &search, merge_pixels);
+
+        if self.check_hints && self.dirty.is_some() {
+            let full = Region::new(0, 0, self.height - 1, self.width - 1);
+
+            assert!(
+                changed_regions(last_frame, frame_data, &full, merge_pixels) == regions,
+                "Frame changed outside of the dirty hints"
+            );
+        }
+
+        regions
+    }
+}
+
+/// Returns the regions covering the pixels which differ between two frames within a search
+/// region. Changed pixels are gathered in to tiles, touching tiles are grouped together, and
+/// the group regions are then merged while merging adds no more than merge_pixels pixels
+fn changed_regions<P: Pixel>(
+    last_frame: &Canvas<P>,
+    frame_data: &Canvas<P>,
+    search: &Region,
+    merge_pixels: usize,
+) -> Vec<Region> {
+    // Tiles are aligned to the frame so the result doesn't depend on the search region
+    let tile_left = search.left() as usize / TILE_SIZE;
+    let tile_top = search.top() as usize / TILE_SIZE;
+    let tiles_across = (search.left() + search.width() - 1) as usize / TILE_SIZE - tile_left + 1;
+    let tiles_down = (search.top() + search.height() - 1) as usize / TILE_SIZE - tile_top + 1;
+
+    let mut tiles = vec![Region::max_init(); tiles_across * tiles_down];
+
+    // Process each row
+    for y in search.y_range() {
+        let l1 = &last_frame.row(y)[search.x_range()];
+        let l2 = &frame_data.row(y)[search.x_range()];
+        let tile_row = (y / TILE_SIZE - tile_top) * tiles_across;
+
+        // Process each pixel
+        for (x, _) in l1
+            .iter()
+            .zip(l2.iter())
+            .enumerate()
+            .filter(|(_, (p1, p2))| *p1 != *p2)
+        {
+            let x = search.left() as usize + x;
+
+            tiles[tile_row + x / TILE_SIZE - tile_left].max_add(x as u16, y as u16)
+        }
+    }
+
+    // Group touching changed tiles
+    let mut regions = Vec::new();
+    let mut stack = Vec::new();
+
+    for start in 0..tiles.len() {
+        if !tiles[start].max_valid() {
+            continue;
+        }
+
+        let mut region = Region::max_init();
+        stack.push(start);
+
+        while let Some(tile) = stack.pop() {
+            if !tiles[tile].max_valid() {
+                continue;
+            }
+
+            region.union(&tiles[tile]);
+            tiles[tile] = Region::max_init();
+
+            let (tx, ty) = (tile % tiles_across, tile / tiles_across);
+
+            for ny in ty.saturating_sub(1)..=(ty + 1).min(tiles_down - 1) {
+                for nx in tx.saturating_sub(1)..=(tx + 1).min(tiles_across - 1) {
+                    if tiles[ny * tiles_across + nx].max_valid() {
+                        stack.push(ny * tiles_across + nx);
+                    }
                 }
             }
+        }
 
-            if region.max_valid() {
-                Some(region)
-            } else {
-                None
+        regions.push(region);
+    }
+
+    if regions.len() > MAX_REGIONS {
+        // Too fragmented - use a single region
+        limit_regions(&mut regions, 1);
+    } else {
+        // Merge regions which are cheaper to encode together
+        while let Some((i, j, extra)) = cheapest_merge(&regions) {
+            if extra > merge_pixels {
+                break;
+            }
+
+            let other = regions.remove(j);
+            regions[i].union(&other);
+        }
+    }
+
+    regions
+}
+
+/// Merges regions together until there are at most max_regions, cheapest merges first
+fn limit_regions(regions: &mut Vec<Region>, max_regions: usize) {
+    if regions.len() <= max_regions {
+        return;
+    }
+
+    if max_regions <= 1 {
+        let mut region = regions.swap_remove(0);
+
+        for other in regions.iter() {
+            region.union(other);
+        }
+
+        *regions = vec![region];
+
+        return;
+    }
+
+    while regions.len() > max_regions {
+        let (i, j, _) = cheapest_merge(regions).unwrap();
+
+        let other = regions.remove(j);
+        regions[i].union(&other);
+    }
+}
+
+/// Finds the pair of regions which adds the fewest pixels when merged. Returns the indexes of the
+/// pair and the number of extra pixels, or None if there are fewer than two regions
+fn cheapest_merge(regions: &[Region]) -> Option<(usize, usize, usize)> {
+    let mut best = None;
+
+    for (i, r1) in regions.iter().enumerate() {
+        for (j, r2) in regions.iter().enumerate().skip(i + 1) {
+            let mut union = r1.clone();
+            union.union(r2);
+
+            let extra = union.area().saturating_sub(r1.area() + r2.area());
+
+            if best.is_none_or(|(_, _, best_extra)| extra < best_extra) {
+                best = Some((i, j, extra));
             }
-        } else {
-            // No previous frame
-            Some(Region::new(0, 0, self.height - 1, self.width - 1))
         }
     }
+
+    best
+}
+
+impl FrameSink for Gif {
+    fn dimensions(&self) -> (u16, u16) {
+        Gif::dimensions(self)
+    }
+
+    fn true_colour(&self) -> bool {
+        false
+    }
+
+    fn draw_frame(&mut self, frame_data: &Canvas, delay: u16) -> Result<(), Box<dyn Error>> {
+        Gif::draw_frame(self, frame_data, delay)
+    }
+
+    fn draw_rgb_frame(&mut self, frame_data: &RgbCanvas, delay: u16) -> Result<(), Box<dyn Error>> {
+        Gif::draw_rgb_frame(self, frame_data, delay)
+    }
+
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        Gif::delay(self, delay)
+    }
+
+    fn mark_dirty(&mut self, x: usize, y: usize, width: usize, height: usize) {
+        Gif::mark_dirty(self, x, y, width, height)
+    }
+
+    /// The GIF is completed when the encoder is dropped
+    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
 }
 
 /// Action to take if the next frame is identical to the last
@@ -202,3 +564,217 @@ pub enum IdenticalAction {
     Ignore,
     Delay,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use gif::{ColorOutput, DecodeOptions};
+
+    use super::*;
+
+    /// Decoded GIF frame
+    struct Decoded {
+        /// Full image after drawing the frame
+        image: Vec<u8>,
+        delay: u16,
+        /// Left, top, width and height of the frame
+        rect: (u16, u16, u16, u16),
+        transparent: Option<u8>,
+    }
+
+    /// Decodes a GIF, returning each frame which isn't an empty delay frame
+    fn decode(file: &str) -> Vec<Decoded> {
+        let mut options = DecodeOptions::new();
+        options.set_color_output(ColorOutput::Indexed);
+
+        let mut decoder = options.read_info(File::open(file).unwrap()).unwrap();
+        let width = decoder.width() as usize;
+        let mut image = vec![0; width * decoder.height() as usize];
+        let mut frames = Vec::new();
+
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            if frame.width == 1 && frame.height == 1 && frame.transparent == Some(0) {
+                continue;
+            }
+
+            for (i, &pix) in frame.buffer.iter().enumerate() {
+                if Some(pix) != frame.transparent {
+                    let x = frame.left as usize + i % frame.width as usize;
+                    let y = frame.top as usize + i / frame.width as usize;
+
+                    image[y * width + x] = pix;
+                }
+            }
+
+            frames.push(Decoded {
+                image: image.clone(),
+                delay: frame.delay,
+                rect: (frame.left, frame.top, frame.width, frame.height),
+                transparent: frame.transparent,
+            });
+        }
+
+        frames
+    }
+
+    /// Scales a canvas up as the GIF would
+    fn scaled(canvas: &Canvas, x_scale: usize, y_scale: usize) -> Vec<u8> {
+        (0..canvas.height())
+            .flat_map(|y| std::iter::repeat_n(y, y_scale))
+            .flat_map(|y| {
+                canvas
+                    .row(y)
+                    .iter()
+                    .flat_map(|&pix| std::iter::repeat_n(pix, x_scale))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_frames() {
+        let file = env::temp_dir().join(format!("aoc-gif-{}-frames.gif", std::process::id()));
+        let file = file.to_str().unwrap();
+
+        let palette = [[0, 0, 0], [255, 0, 0], [0, 255, 0]];
+        let mut gif = Gif::new(file, &palette, 6, 4, 2, 3)
+            .unwrap()
+            .with_hint_checks();
+        let mut frame = gif.empty_frame();
+        let mut expected = Vec::new();
+
+        gif.draw_frame(&frame, 5).unwrap();
+        expected.push(scaled(&frame, 2, 3));
+
+        // Unchanged frame is skipped
+        gif.draw_frame(&frame, 5).unwrap();
+
+        frame.fill_rect(1, 1, 2, 2, 1);
+        gif.draw_frame(&frame, 5).unwrap();
+        expected.push(scaled(&frame, 2, 3));
+
+        // Hinted change
+        frame.set(5, 3, 2);
+        gif.mark_dirty(4, 2, 10, 10);
+        gif.draw_frame(&frame, 5).unwrap();
+        expected.push(scaled(&frame, 2, 3));
+
+        drop(gif);
+
+        let frames = decode(file);
+
+        assert_eq!(
+            frames.into_iter().map(|f| f.image).collect::<Vec<_>>(),
+            expected
+        );
+
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_regions() {
+        let file = env::temp_dir().join(format!("aoc-gif-{}-regions.gif", std::process::id()));
+        let file = file.to_str().unwrap();
+
+        let mut gif = Gif::new(file, &[[0, 0, 0], [255, 255, 255]], 64, 64, 2, 1).unwrap();
+        let mut frame = gif.empty_frame();
+
+        gif.draw_frame(&frame, 10).unwrap();
+
+        // Changes in opposite corners are written as separate frames sharing the delay
+        frame.set(1, 2, 1);
+        frame.set(62, 60, 1);
+        frame.set(63, 61, 1);
+        gif.draw_frame(&frame, 10).unwrap();
+        let two_regions = scaled(&frame, 2, 1);
+
+        // Not enough delay to split
+        frame.set(0, 0, 1);
+        frame.set(63, 63, 1);
+        gif.draw_frame(&frame, 3).unwrap();
+
+        // Nearby changes are merged
+        frame.fill_rect(20, 20, 4, 4, 1);
+        frame.fill_rect(25, 20, 4, 4, 1);
+        gif.draw_frame(&frame, 10).unwrap();
+
+        drop(gif);
+
+        let frames = decode(file);
+
+        assert_eq!(
+            frames.iter().map(|f| (f.rect, f.delay)).collect::<Vec<_>>(),
+            vec![
+                ((0, 0, 128, 64), 10),
+                ((2, 2, 2, 1), 2),
+                ((124, 60, 4, 2), 8),
+                ((0, 0, 128, 64), 3),
+                ((40, 20, 18, 4), 10),
+            ]
+        );
+        assert_eq!(frames[2].image, two_regions);
+        assert_eq!(frames[4].image, scaled(&frame, 2, 1));
+
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_transparent_deltas() {
+        let file = env::temp_dir().join(format!("aoc-gif-{}-deltas.gif", std::process::id()));
+        let file = file.to_str().unwrap();
+
+        let palette = [[0, 0, 0], [255, 0, 0], [0, 255, 0], [0, 0, 0]];
+        let mut gif = Gif::new(file, &palette, 8, 8, 1, 1)
+            .unwrap()
+            .with_transparent_deltas(3);
+        let mut frame = gif.empty_frame();
+        let mut expected = Vec::new();
+
+        frame.fill_rect(0, 0, 8, 4, 1);
+        gif.draw_frame(&frame, 5).unwrap();
+        expected.push((scaled(&frame, 1, 1), None));
+
+        // Unchanged pixels in the region are transparent
+        frame.set(1, 1, 2);
+        frame.set(6, 6, 2);
+        gif.draw_frame(&frame, 2).unwrap();
+        expected.push((scaled(&frame, 1, 1), Some(3)));
+
+        // Changed pixel in the transparent colour
+        frame.set(2, 2, 3);
+        gif.draw_frame(&frame, 2).unwrap();
+        expected.push((scaled(&frame, 1, 1), None));
+
+        drop(gif);
+
+        let frames = decode(file);
+
+        assert_eq!(
+            frames
+                .into_iter()
+                .map(|f| (f.image, f.transparent))
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the dirty hints")]
+    fn test_bad_hint() {
+        let file = env::temp_dir().join(format!("aoc-gif-{}-hint.gif", std::process::id()));
+        let file = file.to_str().unwrap();
+
+        let mut gif = Gif::new(file, &[[0, 0, 0], [255, 255, 255]], 4, 4, 1, 1)
+            .unwrap()
+            .with_hint_checks();
+        let mut frame = gif.empty_frame();
+
+        gif.draw_frame(&frame, 5).unwrap();
+
+        frame.set(0, 0, 1);
+        gif.mark_dirty(2, 2, 1, 1);
+        gif.draw_frame(&frame, 5).unwrap();
+    }
+}
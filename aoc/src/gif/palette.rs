@@ -0,0 +1,302 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Maximum number of colours in a GIF palette
+pub const MAX_COLOURS: usize = 256;
+
+/// Error building a palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteError {
+    /// Adding the named entry would take the palette over 256 colours
+    Full {
+        name: String,
+        needed: usize,
+        free: usize,
+    },
+    /// A colour or ramp with the name already exists
+    DuplicateName(String),
+    /// A ramp needs at least two stops and one step
+    InvalidRamp(String),
+}
+
+impl Display for PaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaletteError::Full { name, needed, free } => write!(
+                f,
+                "Palette full: '{name}' needs {needed} colours but only {free} of {MAX_COLOURS} are free"
+            ),
+            PaletteError::DuplicateName(name) => write!(f, "Palette already contains '{name}'"),
+            PaletteError::InvalidRamp(name) => write!(
+                f,
+                "Ramp '{name}' needs at least two stops and one step"
+            ),
+        }
+    }
+}
+
+impl Error for PaletteError {}
+
+/// Handle to a run of consecutive palette entries added by [`Palette::linear`] or
+/// [`Palette::gradient`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ramp {
+    start: u8,
+    len: usize,
+}
+
+impl Ramp {
+    /// Returns the number of colours in the ramp
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the ramp has no colours (never the case for ramps from a palette)
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the palette index of the nth colour in the ramp
+    #[inline]
+    pub fn index(&self, n: usize) -> u8 {
+        assert!(n < self.len, "Ramp index {n} out of range 0..{}", self.len);
+        self.start + n as u8
+    }
+
+    /// Returns the palette index of the colour a fraction num / denom along the ramp.
+    /// Fractions outside 0 to 1 are clamped to the ends
+    pub fn at(&self, num: usize, denom: usize) -> u8 {
+        let n = ((self.len - 1) * num.min(denom)) / denom.max(1);
+
+        self.index(n)
+    }
+}
+
+/// Builds a GIF palette from named fixed colours and gradient ramps
+#[derive(Debug, Default, Clone)]
+pub struct Palette {
+    colours: Vec<[u8; 3]>,
+    names: HashMap<String, Entry>,
+}
+
+/// Named palette entry
+#[derive(Debug, Clone, Copy)]
+enum Entry {
+    Colour(u8),
+    Ramp(Ramp),
+}
+
+impl Palette {
+    /// Creates an empty palette
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the colours in the palette in index order
+    #[inline]
+    pub fn colours(&self) -> &[[u8; 3]] {
+        &self.colours
+    }
+
+    /// Returns the number of colours in the palette
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.colours.len()
+    }
+
+    /// Returns true if the palette has no colours
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.colours.is_empty()
+    }
+
+    /// Adds a named fixed colour, returning its palette index
+    pub fn colour(&mut self, name: &str, rgb: [u8; 3]) -> Result<u8, PaletteError> {
+        let start = self.reserve(name, 1)?;
+
+        self.colours.push(rgb);
+        self.names.insert(name.to_string(), Entry::Colour(start));
+
+        Ok(start)
+    }
+
+    /// Adds a named ramp of steps colours fading linearly from one colour to another
+    pub fn linear(
+        &mut self,
+        name: &str,
+        from: [u8; 3],
+        to: [u8; 3],
+        steps: usize,
+    ) -> Result<Ramp, PaletteError> {
+        self.gradient(name, &[from, to], steps)
+    }
+
+    /// Adds a named ramp of steps colours fading through evenly spaced stops. The first and last
+    /// colours of the ramp are the first and last stops
+    pub fn gradient(
+        &mut self,
+        name: &str,
+        stops: &[[u8; 3]],
+        steps: usize,
+    ) -> Result<Ramp, PaletteError> {
+        if stops.len() < 2 || steps == 0 {
+            return Err(PaletteError::InvalidRamp(name.to_string()));
+        }
+
+        let start = self.reserve(name, steps)?;
+        let segments = stops.len() - 1;
+        let denom = (steps - 1).max(1);
+
+        for step in 0..steps {
+            // Position along the ramp in units of 1 / denom segments
+            let pos = step * segments;
+            let segment = (pos / denom).min(segments - 1);
+            let offset = pos - segment * denom;
+
+            let (from, to) = (stops[segment], stops[segment + 1]);
+
+            self.colours.push(std::array::from_fn(|c| {
+                let (from, to) = (from[c] as usize, to[c] as usize);
+
+                // Round to the nearest value
+                ((from * (denom - offset) + to * offset + denom / 2) / denom) as u8
+            }));
+        }
+
+        let ramp = Ramp { start, len: steps };
+
+        self.names.insert(name.to_string(), Entry::Ramp(ramp));
+
+        Ok(ramp)
+    }
+
+    /// Returns the index of a named fixed colour
+    pub fn index(&self, name: &str) -> Option<u8> {
+        match self.names.get(name) {
+            Some(Entry::Colour(index)) => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Returns the handle of a named ramp
+    pub fn ramp(&self, name: &str) -> Option<Ramp> {
+        match self.names.get(name) {
+            Some(Entry::Ramp(ramp)) => Some(*ramp),
+            _ => None,
+        }
+    }
+
+    /// Checks there is room for a new named entry, returning its first index
+    fn reserve(&self, name: &str, needed: usize) -> Result<u8, PaletteError> {
+        if self.names.contains_key(name) {
+            return Err(PaletteError::DuplicateName(name.to_string()));
+        }
+
+        let free = MAX_COLOURS - self.colours.len();
+
+        if needed > free {
+            return Err(PaletteError::Full {
+                name: name.to_string(),
+                needed,
+                free,
+            });
+        }
+
+        Ok(self.colours.len() as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette() {
+        let mut palette = Palette::new();
+
+        assert_eq!(palette.colour("black", [0, 0, 0]), Ok(0));
+        assert_eq!(palette.colour("white", [255, 255, 255]), Ok(1));
+
+        let grey = palette
+            .linear("grey", [0, 0, 0], [200, 100, 50], 5)
+            .unwrap();
+
+        assert_eq!(grey.len(), 5);
+        assert_eq!(grey.index(0), 2);
+        assert_eq!(grey.index(4), 6);
+        assert_eq!(grey.at(1, 2), 4);
+        assert_eq!(grey.at(3, 2), 6);
+        assert_eq!(
+            &palette.colours()[2..],
+            &[
+                [0, 0, 0],
+                [50, 25, 13],
+                [100, 50, 25],
+                [150, 75, 38],
+                [200, 100, 50]
+            ]
+        );
+
+        let multi = palette
+            .gradient("multi", &[[0, 0, 0], [100, 0, 0], [100, 200, 0]], 5)
+            .unwrap();
+
+        assert_eq!(
+            &palette.colours()[multi.index(0) as usize..],
+            &[
+                [0, 0, 0],
+                [50, 0, 0],
+                [100, 0, 0],
+                [100, 100, 0],
+                [100, 200, 0]
+            ]
+        );
+
+        assert_eq!(palette.index("white"), Some(1));
+        assert_eq!(palette.index("grey"), None);
+        assert_eq!(palette.ramp("grey"), Some(grey));
+        assert_eq!(palette.ramp("none"), None);
+        assert_eq!(palette.len(), 12);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut palette = Palette::new();
+
+        palette.colour("black", [0, 0, 0]).unwrap();
+
+        assert_eq!(
+            palette.colour("black", [1, 1, 1]),
+            Err(PaletteError::DuplicateName("black".to_string()))
+        );
+        assert!(palette.gradient("one", &[[0, 0, 0]], 10).is_err());
+        assert!(palette.linear("none", [0, 0, 0], [1, 1, 1], 0).is_err());
+
+        let error = palette
+            .linear("big", [0, 0, 0], [255, 255, 255], 256)
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Palette full: 'big' needs 256 colours but only 255 of 256 are free"
+        );
+
+        // Exactly full
+        let ramp = palette
+            .linear("big", [0, 0, 0], [255, 255, 255], 255)
+            .unwrap();
+        assert_eq!(ramp.index(254), 255);
+        assert_eq!(palette.len(), MAX_COLOURS);
+        assert!(palette.colour("extra", [0, 0, 0]).is_err());
+
+        // A single step ramp is the first stop
+        let mut palette = Palette::new();
+        palette.linear("one", [10, 20, 30], [0, 0, 0], 1).unwrap();
+        assert_eq!(palette.colours(), &[[10, 20, 30]]);
+    }
+}
@@ -0,0 +1,502 @@
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use png::{AdaptiveFilterType, BitDepth, ColorType, Compression, Decoder, Encoder};
+
+use super::{canvas::RgbCanvas, changed_regions, limit_regions, sink::FrameSink, Canvas, Region};
+
+/// Creates a PNG encoder for an 8 bit RGB image
+fn encoder<W: Write>(writer: W, width: u32, height: u32) -> Encoder<'static, W> {
+    let mut encoder = Encoder::new(writer, width, height);
+
+    encoder.set_color(ColorType::Rgb);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_adaptive_filter(AdaptiveFilterType::Adaptive);
+
+    // The crate defaults to fast compression, which gives much larger animations
+    encoder.set_compression(Compression::Default);
+
+    encoder
+}
+
+/// Converts frames to scaled RGB bytes, keeping a copy of the last frame output
+struct Frames {
+    width: u16,
+    height: u16,
+    x_scale: u16,
+    y_scale: u16,
+    palette: Vec<[u8; 3]>,
+    /// Copy of the last frame output
+    last_frame: Option<RgbCanvas>,
+    /// Reused frame for converting palette indexes
+    converted: Option<RgbCanvas>,
+    /// Scaled RGB bytes for the frame being written
+    out_buffer: Vec<u8>,
+}
+
+impl Frames {
+    fn new(palette: &[[u8; 3]], width: u16, height: u16, x_scale: u16, y_scale: u16) -> Self {
+        Self {
+            width,
+            height,
+            x_scale,
+            y_scale,
+            palette: palette.to_vec(),
+            last_frame: None,
+            converted: None,
+            out_buffer: Vec::new(),
+        }
+    }
+
+    /// Returns the dimensions of the scaled image
+    fn scaled_dimensions(&self) -> (u32, u32) {
+        (
+            self.width as u32 * self.x_scale as u32,
+            self.height as u32 * self.y_scale as u32,
+        )
+    }
+
+    /// Converts a frame of palette indexes to RGB. The frame should be handed back with
+    /// [`Frames::reuse`] once drawn
+    fn convert(&mut self, frame_data: &Canvas) -> Result<RgbCanvas, Box<dyn Error>> {
+        let mut rgb = self.converted.take().unwrap_or_else(|| {
+            RgbCanvas::filled(frame_data.width(), frame_data.height(), [0, 0, 0])
+        });
+
+        for y in 0..frame_data.height() {
+            for (x, &index) in frame_data.row(y).iter().enumerate() {
+                let Some(colour) = self.palette.get(index as usize) else {
+                    return Err(format!("Colour index {index} is not in the palette").into());
+                };
+
+                rgb.set(x, y, *colour);
+            }
+        }
+
+        Ok(rgb)
+    }
+
+    /// Keeps a converted frame for reuse
+    fn reuse(&mut self, rgb: RgbCanvas) {
+        self.converted = Some(rgb);
+    }
+
+    /// Returns the bounding region of the pixels changed since the last frame and saves the
+    /// frame, or None if the frame is identical
+    fn update(&mut self, frame_data: &RgbCanvas) -> Option<Region> {
+        // Make sure the frame looks like the correct size
+        assert_eq!(frame_data.height(), self.height as usize);
+        assert_eq!(frame_data.width(), self.width as usize);
+
+        let full = Region::new(0, 0, self.height - 1, self.width - 1);
+
+        match &mut self.last_frame {
+            None => {
+                self.last_frame = Some(frame_data.clone());
+
+                Some(full)
+            }
+            Some(last_frame) => {
+                let mut regions = changed_regions(last_frame, frame_data, &full, 0);
+                limit_regions(&mut regions, 1);
+
+                let region = regions.pop()?;
+                last_frame.copy_rows_from(frame_data, region.y_range());
+
+                Some(region)
+            }
+        }
+    }
+
+    /// Scales rows of RGB bytes up
+    fn scale<'a>(&mut self, rows: impl Iterator<Item = &'a [u8]>) -> &[u8] {
+        self.out_buffer.clear();
+
+        for line in rows {
+            let start = self.out_buffer.len();
+
+            for pix in line.chunks_exact(3) {
+                for _ in 0..self.x_scale {
+                    self.out_buffer.extend(pix);
+                }
+            }
+
+            let end = self.out_buffer.len();
+
+            for _ in 1..self.y_scale {
+                self.out_buffer.extend_from_within(start..end);
+            }
+        }
+
+        &self.out_buffer
+    }
+
+    /// Returns the left, top, width and height of a scaled region
+    fn scaled_rect(&self, region: &Region) -> [u32; 4] {
+        [
+            region.left() as u32 * self.x_scale as u32,
+            region.top() as u32 * self.y_scale as u32,
+            region.width() as u32 * self.x_scale as u32,
+            region.height() as u32 * self.y_scale as u32,
+        ]
+    }
+}
+
+/// Changed part of a frame, kept until the frame count is known
+struct PendingFrame {
+    region: Region,
+    delay: u16,
+    /// Unscaled pixels of the region as a PNG image
+    image: Vec<u8>,
+}
+
+/// Animated PNG writer. Only the changed part of each frame is written. The frame count has to
+/// be written before the frames, so they are kept compressed until [`FrameSink::finish`]
+pub struct Apng {
+    frames: Frames,
+    writer: BufWriter<File>,
+    pending: Vec<PendingFrame>,
+}
+
+impl Apng {
+    /// Creates a new animated PNG with a given palette for indexed frames, size and scale
+    pub fn new(
+        file: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            frames: Frames::new(palette, width, height, x_scale, y_scale),
+            writer: BufWriter::new(File::create(file)?),
+            pending: Vec::new(),
+        })
+    }
+}
+
+impl FrameSink for Apng {
+    fn dimensions(&self) -> (u16, u16) {
+        (self.frames.width, self.frames.height)
+    }
+
+    fn true_colour(&self) -> bool {
+        true
+    }
+
+    fn draw_frame(&mut self, frame_data: &Canvas, delay: u16) -> Result<(), Box<dyn Error>> {
+        let rgb = self.frames.convert(frame_data)?;
+        let result = self.draw_rgb_frame(&rgb, delay);
+        self.frames.reuse(rgb);
+
+        result
+    }
+
+    fn draw_rgb_frame(&mut self, frame_data: &RgbCanvas, delay: u16) -> Result<(), Box<dyn Error>> {
+        let Some(region) = self.frames.update(frame_data) else {
+            // Identical frames are skipped
+            return Ok(());
+        };
+
+        let mut image = Vec::new();
+        let mut writer =
+            encoder(&mut image, region.width() as u32, region.height() as u32).write_header()?;
+
+        let pixels = region
+            .y_range()
+            .flat_map(|y| frame_data.row(y)[region.x_range()].as_flattened())
+            .copied()
+            .collect::<Vec<_>>();
+
+        writer.write_image_data(&pixels)?;
+        writer.finish()?;
+
+        self.pending.push(PendingFrame {
+            region,
+            delay,
+            image,
+        });
+
+        Ok(())
+    }
+
+    /// Delays before the first frame are ignored
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        if let Some(frame) = self.pending.last_mut() {
+            frame.delay = frame.delay.saturating_add(delay);
+        }
+
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<(), Box<dyn Error>> {
+        if self.pending.is_empty() {
+            Err("No frames drawn")?
+        }
+
+        let (width, height) = self.frames.scaled_dimensions();
+        let mut encoder = encoder(&mut self.writer, width, height);
+
+        // Repeat infinitely
+        encoder.set_animated(self.pending.len() as u32, 0)?;
+
+        let mut writer = encoder.write_header()?;
+        let mut pixels = Vec::new();
+
+        // The first frame is the whole image, and is also the default image
+        for frame in &self.pending {
+            let [left, top, width, height] = self.frames.scaled_rect(&frame.region);
+
+            writer.reset_frame_position()?;
+            writer.set_frame_dimension(width, height)?;
+            writer.set_frame_position(left, top)?;
+            writer.set_frame_delay(frame.delay, 100)?;
+
+            let mut reader = Decoder::new(frame.image.as_slice()).read_info()?;
+            pixels.resize(reader.output_buffer_size(), 0);
+            let info = reader.next_frame(&mut pixels)?;
+
+            let rows = pixels[..info.buffer_size()].chunks_exact(info.line_size);
+            writer.write_image_data(self.frames.scale(rows))?;
+        }
+
+        writer.finish()?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Name of the ffmpeg concat list written by [`PngDir`]
+pub const FRAME_LIST: &str = "frames.txt";
+
+/// Writes each frame to a numbered PNG file in a directory, for use with external video tools.
+/// The frame durations are written to an ffmpeg concat list by [`FrameSink::finish`], for
+/// example `ffmpeg -f concat -i vis/day16/frames.txt day16.mp4`
+pub struct PngDir {
+    frames: Frames,
+    dir: PathBuf,
+    /// File name and delay of each frame written
+    written: Vec<(String, u16)>,
+}
+
+impl PngDir {
+    /// Creates a new PNG directory with a given palette for indexed frames, size and scale
+    pub fn new(
+        dir: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+    ) -> Result<Self, Box<dyn Error>> {
+        fs::create_dir_all(dir)?;
+
+        Ok(Self {
+            frames: Frames::new(palette, width, height, x_scale, y_scale),
+            dir: PathBuf::from(dir),
+            written: Vec::new(),
+        })
+    }
+}
+
+impl FrameSink for PngDir {
+    fn dimensions(&self) -> (u16, u16) {
+        (self.frames.width, self.frames.height)
+    }
+
+    fn true_colour(&self) -> bool {
+        true
+    }
+
+    fn draw_frame(&mut self, frame_data: &Canvas, delay: u16) -> Result<(), Box<dyn Error>> {
+        let rgb = self.frames.convert(frame_data)?;
+        let result = self.draw_rgb_frame(&rgb, delay);
+        self.frames.reuse(rgb);
+
+        result
+    }
+
+    fn draw_rgb_frame(&mut self, frame_data: &RgbCanvas, delay: u16) -> Result<(), Box<dyn Error>> {
+        if self.frames.update(frame_data).is_none() {
+            // Identical frames are skipped
+            return Ok(());
+        }
+
+        // Each file is a whole frame
+        let (width, height) = self.frames.scaled_dimensions();
+        let name = format!("frame{:05}.png", self.written.len());
+        let file = BufWriter::new(File::create(self.dir.join(&name))?);
+
+        let mut writer = encoder(file, width, height).write_header()?;
+        let rows = (0..frame_data.height()).map(|y| frame_data.row(y).as_flattened());
+        writer.write_image_data(self.frames.scale(rows))?;
+        writer.finish()?;
+
+        self.written.push((name, delay));
+
+        Ok(())
+    }
+
+    /// Delays before the first frame are ignored
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>> {
+        if let Some((_, last_delay)) = self.written.last_mut() {
+            *last_delay = last_delay.saturating_add(delay);
+        }
+
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>> {
+        let mut list = String::from("ffconcat version 1.0\n");
+
+        for (name, delay) in &self.written {
+            list += &format!(
+                "file '{name}'\nduration {}.{:02}\n",
+                delay / 100,
+                delay % 100
+            );
+        }
+
+        // The last file is repeated for its duration to be used
+        if let Some((name, _)) = self.written.last() {
+            list += &format!("file '{name}'\n");
+        }
+
+        fs::write(self.dir.join(FRAME_LIST), list)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// Decodes a PNG or APNG, returning the RGB image and delay after each frame
+    fn decode(file: &PathBuf) -> Vec<(Vec<u8>, u16)> {
+        let decoder = ::png::Decoder::new(File::open(file).unwrap());
+        let mut reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        let width = info.width as usize;
+        let mut image = vec![0; width * info.height as usize * 3];
+        let frame_count = info.animation_control().map_or(1, |ac| ac.num_frames);
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let mut frames = Vec::new();
+
+        for _ in 0..frame_count {
+            let output = reader.next_frame(&mut buffer).unwrap();
+            let (left, top, delay) = match reader.info().frame_control() {
+                Some(fc) => (fc.x_offset as usize, fc.y_offset as usize, fc.delay_num),
+                None => (0, 0, 0),
+            };
+
+            for y in 0..output.height as usize {
+                let row = &buffer[y * output.line_size..][..output.width as usize * 3];
+                let start = ((top + y) * width + left) * 3;
+
+                image[start..start + row.len()].copy_from_slice(row);
+            }
+
+            frames.push((image.clone(), delay));
+        }
+
+        frames
+    }
+
+    /// Scales an RGB canvas up to bytes
+    fn scaled(canvas: &RgbCanvas, x_scale: usize, y_scale: usize) -> Vec<u8> {
+        (0..canvas.height())
+            .flat_map(|y| std::iter::repeat_n(y, y_scale))
+            .flat_map(|y| {
+                canvas
+                    .row(y)
+                    .iter()
+                    .flat_map(|pix| std::iter::repeat_n(pix, x_scale))
+                    .flatten()
+                    .copied()
+            })
+            .collect()
+    }
+
+    /// Draws a test animation with indexed and RGB frames, returning the expected images
+    fn draw(sink: &mut dyn FrameSink) -> Vec<(Vec<u8>, u16)> {
+        let mut expected = Vec::new();
+
+        let mut frame = sink.empty_frame();
+        frame.fill_rect(1, 1, 2, 2, 1);
+        sink.draw_frame(&frame, 5).unwrap();
+
+        let mut rgb = sink.empty_rgb_frame();
+        rgb.fill_rect(1, 1, 2, 2, [255, 0, 0]);
+        expected.push((scaled(&rgb, 2, 3), 5));
+
+        // Identical frame is skipped
+        sink.draw_rgb_frame(&rgb, 5).unwrap();
+
+        rgb.set(5, 3, [1, 2, 3]);
+        rgb.set(4, 2, [4, 5, 6]);
+        sink.draw_rgb_frame(&rgb, 3).unwrap();
+        sink.delay(10).unwrap();
+        expected.push((scaled(&rgb, 2, 3), 13));
+
+        expected
+    }
+
+    #[test]
+    fn test_apng() {
+        let file = env::temp_dir().join(format!("aoc-png-{}-anim.png", std::process::id()));
+
+        let mut sink: Box<dyn FrameSink> = Box::new(
+            Apng::new(
+                file.to_str().unwrap(),
+                &[[0, 0, 0], [255, 0, 0]],
+                6,
+                4,
+                2,
+                3,
+            )
+            .unwrap(),
+        );
+        let expected = draw(sink.as_mut());
+        sink.finish().unwrap();
+
+        assert_eq!(decode(&file), expected);
+
+        fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn test_png_dir() {
+        let dir = env::temp_dir().join(format!("aoc-png-{}-dir", std::process::id()));
+
+        let mut sink: Box<dyn FrameSink> = Box::new(
+            PngDir::new(dir.to_str().unwrap(), &[[0, 0, 0], [255, 0, 0]], 6, 4, 2, 3).unwrap(),
+        );
+        let expected = draw(sink.as_mut());
+        sink.finish().unwrap();
+
+        for (i, (image, _)) in expected.iter().enumerate() {
+            let frames = decode(&dir.join(format!("frame{i:05}.png")));
+
+            assert_eq!(&frames[0].0, image);
+        }
+
+        assert_eq!(
+            fs::read_to_string(dir.join(FRAME_LIST)).unwrap(),
+            "ffconcat version 1.0\n\
+            file 'frame00000.png'\nduration 0.05\n\
+            file 'frame00001.png'\nduration 0.13\n\
+            file 'frame00001.png'\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
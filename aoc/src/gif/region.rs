@@ -4,6 +4,7 @@ use std::{
 };
 
 /// Structure describing a rectanglar region
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Region {
     top: u16,
     left: u16,
@@ -42,11 +43,29 @@ impl Region {
         (self.bottom - self.top) + 1
     }
 
+    /// Returns the number of pixels in the region
+    pub fn area(&self) -> usize {
+        self.width() as usize * self.height() as usize
+    }
+
     /// Returns true if the y coordinate is contained in the region
     pub fn contains_y(&self, y: u16) -> bool {
         y >= self.top && y <= self.bottom
     }
 
+    /// Returns a range for all y coordinates
+    pub fn y_range(&self) -> RangeInclusive<usize> {
+        (self.top as usize)..=(self.bottom as usize)
+    }
+
+    /// Extends the region to cover another region
+    pub fn union(&mut self, other: &Region) {
+        self.top = min(self.top, other.top);
+        self.left = min(self.left, other.left);
+        self.bottom = max(self.bottom, other.bottom);
+        self.right = max(self.right, other.right);
+    }
+
     /// Returns a range for all x coordinates
     pub fn x_range(&self) -> RangeInclusive<usize> {
         (self.left as usize)..=(self.right as usize)
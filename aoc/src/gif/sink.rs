@@ -0,0 +1,136 @@
+use std::{env, error::Error};
+
+use super::{
+    canvas::RgbCanvas,
+    png::{Apng, PngDir},
+    Canvas, Gif,
+};
+
+/// Destination for the frames of an animation
+pub trait FrameSink {
+    /// Returns dimensions of the pre-scaled image
+    fn dimensions(&self) -> (u16, u16);
+
+    /// Returns true if RGB frames can use any colour. Otherwise every colour in an RGB frame
+    /// must be in the palette
+    fn true_colour(&self) -> bool;
+
+    /// Outputs a frame of palette indexes
+    fn draw_frame(&mut self, frame_data: &Canvas, delay: u16) -> Result<(), Box<dyn Error>>;
+
+    /// Outputs a frame of RGB colours
+    fn draw_rgb_frame(&mut self, frame_data: &RgbCanvas, delay: u16) -> Result<(), Box<dyn Error>>;
+
+    /// Extends the time the last frame is shown for
+    fn delay(&mut self, delay: u16) -> Result<(), Box<dyn Error>>;
+
+    /// Hints that only pixels in the given rectangle may have changed before the next frame.
+    /// Sinks which don't use hints ignore them
+    fn mark_dirty(&mut self, _x: usize, _y: usize, _width: usize, _height: usize) {}
+
+    /// Completes the output. Must be called after the last frame
+    fn finish(self: Box<Self>) -> Result<(), Box<dyn Error>>;
+
+    /// Returns a new empty frame of palette indexes
+    fn empty_frame(&self) -> Canvas {
+        let (w, h) = self.dimensions();
+
+        Canvas::new(w as usize, h as usize)
+    }
+
+    /// Returns a new black RGB frame
+    fn empty_rgb_frame(&self) -> RgbCanvas {
+        let (w, h) = self.dimensions();
+
+        RgbCanvas::filled(w as usize, h as usize, [0, 0, 0])
+    }
+}
+
+/// Animation output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// GIF file, limited to the palette
+    #[default]
+    Gif,
+    /// Animated PNG file
+    Apng,
+    /// Directory of numbered PNG files with an ffmpeg concat list
+    PngDir,
+}
+
+impl Format {
+    /// Parses a format name
+    pub fn parse(name: &str) -> Result<Self, Box<dyn Error>> {
+        match name {
+            "gif" => Ok(Format::Gif),
+            "apng" => Ok(Format::Apng),
+            "png" => Ok(Format::PngDir),
+            _ => Err(format!(
+                "Unknown output format '{name}' (expected gif, apng or png)"
+            ))?,
+        }
+    }
+
+    /// Gets the format from an optional `--format <name>` command line argument
+    pub fn from_args() -> Result<Self, Box<dyn Error>> {
+        let args = env::args().skip(1).collect::<Vec<_>>();
+        let args = args.iter().map(|a| a.as_str()).collect::<Vec<_>>();
+
+        match args.as_slice() {
+            [] => Ok(Format::default()),
+            ["--format", name] => Self::parse(name),
+            _ => Err("Usage: [--format gif|apng|png]")?,
+        }
+    }
+
+    /// Returns the output path for a path without an extension
+    pub fn path(&self, base: &str) -> String {
+        match self {
+            Format::Gif => format!("{base}.gif"),
+            Format::Apng => format!("{base}.png"),
+            Format::PngDir => base.to_string(),
+        }
+    }
+
+    /// Creates a sink writing to a path without an extension, with a given palette for indexed
+    /// frames, size and scale
+    pub fn create(
+        &self,
+        base: &str,
+        palette: &[[u8; 3]],
+        width: u16,
+        height: u16,
+        x_scale: u16,
+        y_scale: u16,
+    ) -> Result<Box<dyn FrameSink>, Box<dyn Error>> {
+        let path = self.path(base);
+
+        Ok(match self {
+            Format::Gif => Box::new(Gif::new(&path, palette, width, height, x_scale, y_scale)?),
+            Format::Apng => Box::new(Apng::new(&path, palette, width, height, x_scale, y_scale)?),
+            Format::PngDir => Box::new(PngDir::new(
+                &path, palette, width, height, x_scale, y_scale,
+            )?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Format::parse("gif").unwrap(), Format::Gif);
+        assert_eq!(Format::parse("apng").unwrap(), Format::Apng);
+        assert_eq!(Format::parse("png").unwrap(), Format::PngDir);
+        assert_eq!(
+            Format::parse("jpeg").unwrap_err().to_string(),
+            "Unknown output format 'jpeg' (expected gif, apng or png)"
+        );
+
+        assert_eq!(Format::Gif.path("vis/day01"), "vis/day01.gif");
+        assert_eq!(Format::Apng.path("vis/day01"), "vis/day01.png");
+        assert_eq!(Format::PngDir.path("vis/day01"), "vis/day01");
+    }
+}
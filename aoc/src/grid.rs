@@ -0,0 +1,443 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geom::{Dir4, Pos},
+    input::Input,
+};
+
+/// Offsets of the 4 orthogonal neighbours (N, E, S, W)
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 surrounding neighbours, clockwise from N
+const OFFSETS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Parse an input file to a grid with a given character transform
+pub fn parse_grid<T, F>(day: usize, cfn: F) -> Result<Grid<T>, Box<dyn Error>>
+where
+    F: FnMut(char) -> T,
+{
+    Input::new(day)?.parse_grid(cfn)
+}
+
+/// Parse an input string to a grid with a given character transform
+pub fn parse_test_grid<T, F>(test: &str, cfn: F) -> Result<Grid<T>, Box<dyn Error>>
+where
+    F: FnMut(char) -> T,
+{
+    Grid::parse(test, cfn)
+}
+
+/// Two dimensional grid of cells stored in row order, addressed by (x, y)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from a vector of cells in row order
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid size mismatch");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid, calling a function with the (x, y) position of each cell
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self::new(width, height, cells)
+    }
+
+    /// Creates a grid from a vector of equal length rows
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Box<dyn Error>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            Err(format!(
+                "Row {} has width {}, expected {width}",
+                y + 1,
+                rows[y].len()
+            ))?
+        }
+
+        Ok(Self::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    /// Parses a grid from text with a given character transform
+    pub fn parse<F>(text: &str, mut cfn: F) -> Result<Self, Box<dyn Error>>
+    where
+        F: FnMut(char) -> T,
+    {
+        let mut cells = Vec::with_capacity(text.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for line in text.lines() {
+            let start = cells.len();
+            cells.extend(line.chars().map(&mut cfn));
+            let line_width = cells.len() - start;
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => Err(format!(
+                    "Row {} has width {line_width}, expected {width}",
+                    height + 1
+                ))?,
+                _ => (),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+
+    /// Returns the width of the grid
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the grid
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns a reference to the cell at (x, y) or None if out of bounds
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    /// Returns a mutable reference to the cell at (x, y) or None if out of bounds
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns a reference to the cell at (x, y), wrapping the coordinates to the grid
+    /// as if it were tiled infinitely in all directions
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        &self[(x, y)]
+    }
+
+    /// Returns row y as a slice
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns row y as a mutable slice
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows of the grid
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns an iterator over the cells in column x
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "Column {x} out of bounds");
+
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Returns an iterator over the columns of the grid
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns an iterator over the cells in row order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Returns a mutable iterator over the cells in row order
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns an iterator over the (x, y) positions and cells in row order
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, c)| ((i % width, i / width), c))
+    }
+
+    /// Returns the position of the first cell in row order matching a predicate
+    pub fn find<P>(&self, pred: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    /// Returns the in bounds orthogonal neighbours of (x, y) in the order N, E, S, W
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.checked_neighbours(x, y, &OFFSETS4)
+    }
+
+    /// Returns the in bounds surrounding neighbours of (x, y) clockwise from N
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.checked_neighbours(x, y, &OFFSETS8)
+    }
+
+    /// Returns the orthogonal neighbours of (x, y) in the order N, E, S, W, wrapping at the edges
+    pub fn neighbours4_wrapping(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.wrapping_neighbours(x, y, &OFFSETS4)
+    }
+
+    /// Returns the surrounding neighbours of (x, y) clockwise from N, wrapping at the edges
+    pub fn neighbours8_wrapping(
+        &self,
+        x: usize,
+        y: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        self.wrapping_neighbours(x, y, &OFFSETS8)
+    }
+
+    /// Moves one step from a position in a direction, returning None if the new position
+    /// is outside the grid
+    pub fn step(&self, pos: Pos, dir: Dir4) -> Option<Pos> {
+        pos.step_within(dir, self.width, self.height)
+    }
+
+    /// Creates a new grid by applying a function to each cell
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    fn checked_neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx).filter(|&nx| nx < width)?;
+            let ny = y.checked_add_signed(dy).filter(|&ny| ny < height)?;
+
+            Some((nx, ny))
+        })
+    }
+
+    fn wrapping_neighbours(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        offsets.iter().map(move |&(dx, dy)| {
+            (
+                (x as isize + dx).rem_euclid(width) as usize,
+                (y as isize + dy).rem_euclid(height) as usize,
+            )
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to a value
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Returns the grid with rows and columns swapped
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Returns the grid rotated 90 degrees clockwise
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Returns the grid rotated 90 degrees anticlockwise
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        let i = self
+            .index_of(x, y)
+            .unwrap_or_else(|| panic!("Position ({x}, {y}) out of bounds"));
+
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let i = self
+            .index_of(x, y)
+            .unwrap_or_else(|| panic!("Position ({x}, {y}) out of bounds"));
+
+        &mut self.cells[i]
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self[(pos.x, pos.y)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self[(pos.x, pos.y)]
+    }
+}
+
+/// Writes the grid in puzzle text format, one line per row
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for c in row {
+                write!(f, "{c}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Input {
+    /// Parses the input to a grid with a given character transform
+    pub fn parse_grid<T, F>(&self, cfn: F) -> Result<Grid<T>, Box<dyn Error>>
+    where
+        F: FnMut(char) -> T,
+    {
+        Grid::parse(self.as_str()?, cfn).map_err(|e| format!("{}: {e}", self.name()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+abc
+def";
+
+    #[test]
+    fn test_parse_display() {
+        let grid = parse_test_grid(EXAMPLE, |c| c).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), EXAMPLE);
+
+        assert!(parse_test_grid("ab\nc", |c| c).is_err());
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = parse_test_grid(EXAMPLE, |c| c).unwrap();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.find(|&c| c == 'f'), Some((2, 1)));
+        assert_eq!(grid.enumerate().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = parse_test_grid(EXAMPLE, |c| c).unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 2).count(), 3);
+        assert_eq!(
+            grid.neighbours4_wrapping(0, 0).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 0), (0, 1), (2, 0)]
+        );
+        assert_eq!(grid.get_wrapping(-1, 4), &grid[(2, 1)]);
+    }
+}
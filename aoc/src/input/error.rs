@@ -0,0 +1,374 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+};
+
+/// Error parsing a single line of input, optionally locating the problem within the line
+#[derive(Debug)]
+pub struct LineError {
+    message: String,
+    span: Option<Range<usize>>,
+}
+
+impl LineError {
+    /// Creates a new line error with a message
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            span: None,
+        }
+    }
+
+    /// Sets the byte range within the line that the error relates to
+    pub fn at(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Sets the span of the error to the position of a substring of the line.
+    /// The span is left unset if `part` is not a slice of `line`
+    pub fn within(self, line: &str, part: &str) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+
+        if part_start >= line_start && part_start + part.len() <= line_start + line.len() {
+            let start = part_start - line_start;
+            self.at(start..start + part.len())
+        } else {
+            self
+        }
+    }
+}
+
+impl<E: Display> From<E> for LineError {
+    fn from(e: E) -> Self {
+        Self::new(e)
+    }
+}
+
+/// Extension trait to locate the error of a result within a line
+pub trait LineResultExt<T> {
+    /// Converts the error to a line error located at a substring of the line
+    fn within(self, line: &str, part: &str) -> Result<T, LineError>;
+}
+
+impl<T, E: Into<LineError>> LineResultExt<T> for Result<T, E> {
+    fn within(self, line: &str, part: &str) -> Result<T, LineError> {
+        self.map_err(|e| e.into().within(line, part))
+    }
+}
+
+/// Errors from a transform of lines borrowed from an input source, each paired with the line
+/// it relates to
+#[derive(Debug, Default)]
+pub struct LinesError<'a> {
+    errors: Vec<(&'a str, LineError)>,
+}
+
+impl<'a> LinesError<'a> {
+    /// Creates a new empty error list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an error for a borrowed line
+    pub fn push(&mut self, line: &'a str, error: impl Into<LineError>) {
+        self.errors.push((line, error.into()));
+    }
+
+    /// Adds all of the errors from another list
+    pub fn append(&mut self, mut other: LinesError<'a>) {
+        self.errors.append(&mut other.errors);
+    }
+
+    /// Returns true if no errors have been added
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the value if no errors have been added
+    pub fn or_value<T>(self, value: T) -> Result<T, Self> {
+        if self.is_empty() {
+            Ok(value)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Converts to a parse error for a named source, numbering each line by its position
+    /// in the source text. Lines which don't borrow from the text are numbered 0
+    pub(crate) fn into_parse_error(self, source: &str, text: &str) -> ParseError {
+        let start = text.as_ptr() as usize;
+        let mut error = ParseError::new(source);
+
+        for (line, e) in self.errors {
+            let line_no = match (line.as_ptr() as usize).checked_sub(start) {
+                // The whole line must be in the text. Only an empty last line may start at the end
+                Some(offset)
+                    if (offset < text.len() || line.is_empty())
+                        && offset + line.len() <= text.len() =>
+                {
+                    text.as_bytes()[..offset]
+                        .iter()
+                        .filter(|&&b| b == b'\n')
+                        .count()
+                        + 1
+                }
+                _ => 0,
+            };
+
+            error.push(line_no, line, e);
+        }
+
+        error
+    }
+}
+
+/// Error for a line in an input source
+#[derive(Debug)]
+struct SourceLineError {
+    line_no: usize,
+    text: String,
+    error: LineError,
+}
+
+/// Errors found while parsing an input source
+#[derive(Debug)]
+pub struct ParseError {
+    source: String,
+    errors: Vec<SourceLineError>,
+}
+
+impl ParseError {
+    /// Creates a new empty error list for a named input source
+    pub(crate) fn new(source: &str) -> Self {
+        Self {
+            source: source.to_string(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Adds an error for a line (line numbers start at 1)
+    pub(crate) fn push(&mut self, line_no: usize, text: &str, error: LineError) {
+        self.errors.push(SourceLineError {
+            line_no,
+            text: text.to_string(),
+            error,
+        });
+    }
+
+    /// Returns true if no errors have been added
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of lines with errors
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Width of the line number gutter
+        let gutter = self
+            .errors
+            .iter()
+            .map(|e| e.line_no.to_string().len())
+            .max()
+            .unwrap_or(1);
+
+        for (i, e) in self.errors.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            // Convert the byte span to character columns. Spans which aren't a valid slice of
+            // the line are shown without a caret
+            let (col, len) = match &e.error.span {
+                Some(span)
+                    if span.start <= span.end
+                        && span.end <= e.text.len()
+                        && e.text.is_char_boundary(span.start)
+                        && e.text.is_char_boundary(span.end) =>
+                {
+                    (
+                        e.text[..span.start].chars().count(),
+                        e.text[span.clone()].chars().count().max(1),
+                    )
+                }
+                _ => (0, 0),
+            };
+
+            writeln!(f, "error: {}", e.error.message)?;
+            writeln!(
+                f,
+                "{:gutter$}--> {}:{}:{}",
+                "",
+                self.source,
+                e.line_no,
+                col + 1
+            )?;
+            writeln!(f, "{:gutter$} |", "")?;
+            writeln!(f, "{:>gutter$} | {}", e.line_no, e.text)?;
+
+            if len > 0 {
+                writeln!(f, "{:gutter$} | {}{}", "", " ".repeat(col), "^".repeat(len))?;
+            }
+        }
+
+        if self.errors.len() > 1 {
+            write!(f, "\n{} lines failed to parse", self.errors.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let line = "Game 3: x blue";
+        let part = &line[8..9];
+
+        let mut error = ParseError::new("day02.txt");
+        error.push(3, line, "x".parse::<u16>().within(line, part).unwrap_err());
+
+        assert_eq!(
+            error.to_string(),
+            "\
+error: invalid digit found in string
+ --> day02.txt:3:9
+  |
+3 | Game 3: x blue
+  |         ^
+"
+        );
+    }
+
+    #[test]
+    fn test_lines_error() {
+        let text = "a\nb: 1\n\nc: x";
+        let lines = text.lines().collect::<Vec<_>>();
+
+        let mut errors = LinesError::new();
+        assert!(errors.is_empty());
+
+        errors.push(lines[1], "first");
+        let mut other = LinesError::new();
+        other.push(
+            lines[3],
+            LineError::new("second").within(lines[3], &lines[3][3..]),
+        );
+        other.push("elsewhere", "third");
+        errors.append(other);
+
+        assert_eq!(
+            errors.into_parse_error("test", text).to_string(),
+            "\
+error: first
+ --> test:2:1
+  |
+2 | b: 1
+
+error: second
+ --> test:4:4
+  |
+4 | c: x
+  |    ^
+
+error: third
+ --> test:0:1
+  |
+0 | elsewhere
+
+3 lines failed to parse"
+        );
+
+        assert_eq!(LinesError::new().or_value(1).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_lines_outside_text() {
+        // The rest of the buffer is next to the text but not in it
+        let buffer = "a\nbc: x";
+        let (text, after) = buffer.split_at(3);
+
+        let mut errors = LinesError::new();
+        errors.push(&text[2..], "inside");
+        errors.push(after, "adjacent");
+        errors.push(&buffer[2..5], "overlapping");
+        errors.push(&text[3..], "empty last");
+
+        assert_eq!(
+            errors.into_parse_error("test", text).to_string(),
+            "\
+error: inside
+ --> test:2:1
+  |
+2 | b
+
+error: adjacent
+ --> test:0:1
+  |
+0 | c: x
+
+error: overlapping
+ --> test:0:1
+  |
+0 | bc:
+
+error: empty last
+ --> test:2:1
+  |
+2 |\x20
+
+4 lines failed to parse"
+        );
+    }
+
+    #[test]
+    fn test_bad_spans() {
+        let line = "Gäme 3: x";
+
+        let mut error = ParseError::new("day02.txt");
+        #[allow(clippy::reversed_empty_ranges)]
+        error.push(1, line, LineError::new("reversed").at(5..2));
+        error.push(2, line, LineError::new("mid char").at(2..3));
+        error.push(3, line, LineError::new("past end").at(8..20));
+        error.push(4, line, LineError::new("multi-byte").at(1..3));
+
+        assert_eq!(
+            error.to_string(),
+            "\
+error: reversed
+ --> day02.txt:1:1
+  |
+1 | Gäme 3: x
+
+error: mid char
+ --> day02.txt:2:1
+  |
+2 | Gäme 3: x
+
+error: past end
+ --> day02.txt:3:1
+  |
+3 | Gäme 3: x
+
+error: multi-byte
+ --> day02.txt:4:2
+  |
+4 | Gäme 3: x
+  |  ^
+
+4 lines failed to parse"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    error::Error,
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// User agent sent with requests, as asked for by the Advent of Code website
+const USER_AGENT: &str = "aoc2023 input fetcher (andy.ward.uk@gmail.com)";
+
+/// Source of puzzle inputs which are not yet cached locally
+pub trait Fetcher {
+    /// Downloads the puzzle input for a year and day
+    fn fetch(&self, year: u16, day: usize) -> Result<Vec<u8>, Box<dyn Error>>;
+}
+
+/// Fetches puzzle inputs from the Advent of Code website with curl, using a session token
+pub struct CurlFetcher {
+    session: String,
+}
+
+impl CurlFetcher {
+    /// Creates a new fetcher with a session token
+    pub fn new(session: &str) -> Self {
+        Self {
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a new fetcher with the session token read from a file
+    pub fn from_token_file(file: &str) -> Result<Self, Box<dyn Error>> {
+        let session =
+            fs::read_to_string(file).map_err(|e| format!("Unable to read {file}: {e}"))?;
+
+        if session.trim().is_empty() {
+            Err(format!("No token in {file}"))?;
+        }
+
+        Ok(Self::new(&session))
+    }
+
+    /// Returns the curl config setting the session cookie. This is passed on stdin rather
+    /// than the command line so the token can't be seen in the process list
+    fn config(&self) -> String {
+        let session = self.session.replace('\\', "\\\\").replace('"', "\\\"");
+
+        format!("cookie = \"session={session}\"\n")
+    }
+}
+
+impl Fetcher for CurlFetcher {
+    fn fetch(&self, year: u16, day: usize) -> Result<Vec<u8>, Box<dyn Error>> {
+        let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+
+        let mut child = Command::new("curl")
+            .args(["-sS", "--fail", "--user-agent", USER_AGENT])
+            .args(["--config", "-"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Unable to run curl: {e}"))?;
+
+        // Closes stdin when dropped so curl sees the end of the config
+        child
+            .stdin
+            .take()
+            .ok_or("Unable to write to curl")?
+            .write_all(self.config().as_bytes())?;
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            Err(format!(
+                "Failed to download {url}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))?;
+        }
+
+        Ok(output.stdout)
+    }
+}
+
+/// Serves puzzle inputs from a local directory laid out like the website
+/// (`<root>/<year>/day/<day>/input`). Stands in for the website in tests
+pub struct LocalFetcher {
+    root: PathBuf,
+}
+
+impl LocalFetcher {
+    /// Creates a new fetcher serving files from a root directory
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Fetcher for LocalFetcher {
+    fn fetch(&self, year: u16, day: usize) -> Result<Vec<u8>, Box<dyn Error>> {
+        let path = self
+            .root
+            .join(year.to_string())
+            .join("day")
+            .join(day.to_string())
+            .join("input");
+
+        fs::read(&path).map_err(|e| format!("Failed to fetch {}: {e}", path.display()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curl_config() {
+        assert_eq!(
+            CurlFetcher::new("abc123\n").config(),
+            "cookie = \"session=abc123\"\n"
+        );
+        assert_eq!(
+            CurlFetcher::new("a\"b\\c").config(),
+            "cookie = \"session=a\\\"b\\\\c\"\n"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+/// Iterator over the lines of a byte slice, borrowing from the slice.
+/// Lines are split on `\n` with any trailing `\r` removed, matching `str::lines`
+pub struct ByteLines<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> ByteLines<'a> {
+    /// Creates a new line iterator over a byte slice
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { remaining: bytes }
+    }
+}
+
+impl<'a> Iterator for ByteLines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let line = match self.remaining.iter().position(|&b| b == b'\n') {
+            Some(pos) => {
+                let line = &self.remaining[..pos];
+                self.remaining = &self.remaining[pos + 1..];
+                line
+            }
+            None => std::mem::take(&mut self.remaining),
+        };
+
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_lines() {
+        for s in ["", "a", "a\n", "a\nbc", "a\r\nbc\r\n", "\n\nx\n", "a\rb\n"] {
+            let bytes = ByteLines::new(s.as_bytes()).collect::<Vec<_>>();
+            let strs = s.lines().map(str::as_bytes).collect::<Vec<_>>();
+
+            assert_eq!(bytes, strs, "{s:?}");
+        }
+    }
+}
@@ -0,0 +1,450 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines, Read};
+
+#[cfg(not(miri))]
+use memmap2::Mmap;
+
+pub use self::error::{LineError, LineResultExt, LinesError, ParseError};
+pub use self::fetch::{CurlFetcher, Fetcher, LocalFetcher};
+pub use self::lines::ByteLines;
+pub use self::resolver::{Resolver, DEFAULT_YEAR, FIRST_YEAR, INPUT_DIR_VAR};
+pub use self::sections::{sections, try_map_lines};
+
+mod error;
+mod fetch;
+mod lines;
+mod resolver;
+mod sections;
+
+/// Parse an input file to a vector with a given transform
+pub fn parse_input_vec<T, F>(day: usize, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(String) -> T,
+{
+    Input::new(day)?.parse_vec(tfn)
+}
+
+/// Parse an input file with a single line with a given transform
+pub fn parse_input_line<T, F>(day: usize, tfn: F) -> Result<T, Box<dyn Error>>
+where
+    F: FnMut(String) -> T,
+{
+    Input::new(day)?.parse_line(tfn)
+}
+
+/// Parse an input file to a vector with a given fallible transform, reporting all lines in error
+pub fn try_parse_input_vec<T, E, F>(day: usize, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<LineError>,
+{
+    Input::new(day)?.try_parse_vec(tfn)
+}
+
+/// Parse an input file with a single line with a given fallible transform
+pub fn try_parse_input_line<T, E, F>(day: usize, tfn: F) -> Result<T, Box<dyn Error>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<LineError>,
+{
+    Input::new(day)?.try_parse_line(tfn)
+}
+
+/// Parse an input file split in to blank line separated sections with a given transform
+pub fn parse_input_sections<T, F>(day: usize, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(&[&str]) -> T,
+{
+    Input::new(day)?.parse_sections(tfn)
+}
+
+/// Parse an input file consisting of a header section and a body with given transforms
+pub fn parse_input_header_body<H, B, FH, FB>(
+    day: usize,
+    hfn: FH,
+    bfn: FB,
+) -> Result<(H, B), Box<dyn Error>>
+where
+    FH: FnOnce(&[&str]) -> H,
+    FB: FnOnce(&[&str]) -> B,
+{
+    Input::new(day)?.parse_header_body(hfn, bfn)
+}
+
+/// Parse an input file consisting of a header section and a body with given fallible transforms,
+/// reporting all lines in error
+pub fn try_parse_input_header_body<H, B, FH, FB>(
+    day: usize,
+    hfn: FH,
+    bfn: FB,
+) -> Result<(H, B), Box<dyn Error>>
+where
+    FH: for<'a> FnOnce(&[&'a str]) -> Result<H, LinesError<'a>>,
+    FB: for<'a> FnOnce(&[&'a str]) -> Result<B, LinesError<'a>>,
+{
+    Input::new(day)?.try_parse_header_body(hfn, bfn)
+}
+
+/// Read a file from the inputs directory to a string
+pub fn read_input_file(file: &str) -> Result<String, Box<dyn Error>> {
+    let mut string = String::new();
+    Input::open(file)?.0.read_to_string(&mut string)?;
+
+    Ok(string)
+}
+
+/// Parse an input string to a vector with a given transform
+pub fn parse_test_vec<T, F>(test: &str, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(String) -> T,
+{
+    let buf = BufReader::new(test.as_bytes());
+    parse_buf_vec(buf.lines(), tfn)
+}
+
+/// Parse an input string to a vector with a given fallible transform, reporting all lines in error
+pub fn try_parse_test_vec<T, E, F>(test: &str, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<LineError>,
+{
+    let buf = BufReader::new(test.as_bytes());
+    try_parse_buf_vec("<test>", buf.lines(), tfn)
+}
+
+/// Parse an input string to a vector with a given borrowing transform
+pub fn parse_test_str_vec<'a, T, F>(test: &'a str, tfn: F) -> Vec<T>
+where
+    F: FnMut(&'a str) -> T,
+{
+    test.lines().map(tfn).collect()
+}
+
+/// Parse an input string to a vector of byte lines with a given borrowing transform
+pub fn parse_test_byte_vec<'a, T, F>(test: &'a str, tfn: F) -> Vec<T>
+where
+    F: FnMut(&'a [u8]) -> T,
+{
+    ByteLines::new(test.as_bytes()).map(tfn).collect()
+}
+
+/// Parse an input string split in to blank line separated sections with a given transform
+pub fn parse_test_sections<'a, T, F>(test: &'a str, tfn: F) -> Vec<T>
+where
+    F: FnMut(&[&'a str]) -> T,
+{
+    sections::parse_sections(&test.lines().collect::<Vec<_>>(), tfn)
+}
+
+/// Parse an input string consisting of a header section and a body with given transforms
+pub fn parse_test_header_body<'a, H, B, FH, FB>(
+    test: &'a str,
+    hfn: FH,
+    bfn: FB,
+) -> Result<(H, B), Box<dyn Error>>
+where
+    FH: FnOnce(&[&'a str]) -> H,
+    FB: FnOnce(&[&'a str]) -> B,
+{
+    sections::parse_header_body(&test.lines().collect::<Vec<_>>(), hfn, bfn)
+}
+
+/// Parse an input string consisting of a header section and a body with given fallible
+/// transforms, reporting all lines in error
+pub fn try_parse_test_header_body<'a, H, B, FH, FB>(
+    test: &'a str,
+    hfn: FH,
+    bfn: FB,
+) -> Result<(H, B), Box<dyn Error>>
+where
+    FH: FnOnce(&[&'a str]) -> Result<H, LinesError<'a>>,
+    FB: FnOnce(&[&'a str]) -> Result<B, LinesError<'a>>,
+{
+    sections::try_parse_header_body("<test>", test, hfn, bfn)
+}
+
+/// Parse a whole input string with a given fallible transform, reporting all lines in error
+pub fn try_parse_test_str<'a, T, F>(test: &'a str, tfn: F) -> Result<T, Box<dyn Error>>
+where
+    F: FnOnce(&'a str) -> Result<T, LinesError<'a>>,
+{
+    tfn(test).map_err(|e| e.into_parse_error("<test>", test).into())
+}
+
+/// Parse an test input file to a vector with a given transform
+pub fn parse_test_input_vec<T, F>(
+    day: usize,
+    example: usize,
+    tfn: F,
+) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(String) -> T,
+{
+    Input::new_example(day, example)?.parse_vec(tfn)
+}
+
+/// Parse an test input file to a vector with a given fallible transform, reporting all lines in error
+pub fn try_parse_test_input_vec<T, E, F>(
+    day: usize,
+    example: usize,
+    tfn: F,
+) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<LineError>,
+{
+    Input::new_example(day, example)?.try_parse_vec(tfn)
+}
+
+/// Memory mapped input
+pub struct Input {
+    name: String,
+    #[cfg(not(miri))]
+    mmap: Mmap,
+    #[cfg(miri)]
+    mmap: String,
+}
+
+impl Input {
+    /// Opens the input file for a given day
+    pub fn new(day: usize) -> Result<Self, Box<dyn Error>> {
+        Self::new_resolved(&Resolver::new(), day)
+    }
+
+    /// Opens the input file for a given day located by a resolver
+    pub fn new_resolved(resolver: &Resolver, day: usize) -> Result<Self, Box<dyn Error>> {
+        let path = resolver.resolve(day)?;
+        let name = path.display().to_string();
+
+        Self::new_from_file(File::open(path)?, name)
+    }
+
+    /// Opens an example input file for a given day
+    pub fn new_example(day: usize, example: usize) -> Result<Self, Box<dyn Error>> {
+        // Open the file
+        let (file, name) = Self::open(&format!("example{day:02}-{example}.txt"))?;
+
+        Self::new_from_file(file, name)
+    }
+
+    /// Returns the path of the opened input file
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Opens a file from the inputs directory, returning the file and its path
+    fn open(file: &str) -> std::io::Result<(File, String)> {
+        Resolver::new().open(file)
+    }
+
+    #[cfg(not(miri))]
+    fn new_from_file(file: File, name: String) -> Result<Self, Box<dyn Error>> {
+        // Memory map it
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Ok(Self { name, mmap })
+    }
+
+    #[cfg(miri)]
+    fn new_from_file(mut file: File, name: String) -> Result<Self, Box<dyn Error>> {
+        // Read to string
+        let mut mmap = String::new();
+        file.read_to_string(&mut mmap)?;
+
+        Ok(Self { name, mmap })
+    }
+
+    /// Parses the input to a vector with a given transform
+    pub fn parse_vec<T, F>(&self, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        F: FnMut(String) -> T,
+    {
+        parse_buf_vec(self.lines(), tfn)
+    }
+
+    /// Parses the first line of the input with a given transform
+    pub fn parse_line<T, F>(&self, tfn: F) -> Result<T, Box<dyn Error>>
+    where
+        F: FnMut(String) -> T,
+    {
+        parse_buf_line(self.lines(), tfn)
+    }
+
+    /// Parses the input to a vector with a given fallible transform, reporting all lines in error
+    pub fn try_parse_vec<T, E, F>(&self, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        F: FnMut(&str) -> Result<T, E>,
+        E: Into<LineError>,
+    {
+        try_parse_buf_vec(&self.name, self.lines(), tfn)
+    }
+
+    /// Parses the first line of the input with a given fallible transform
+    pub fn try_parse_line<T, E, F>(&self, mut tfn: F) -> Result<T, Box<dyn Error>>
+    where
+        F: FnMut(&str) -> Result<T, E>,
+        E: Into<LineError>,
+    {
+        let line = self.lines().next().ok_or("No line found in input")??;
+
+        tfn(&line).map_err(|e| {
+            let mut error = ParseError::new(&self.name);
+            error.push(1, &line, e.into());
+            error.into()
+        })
+    }
+
+    /// Returns the raw bytes of the input
+    pub fn as_bytes(&self) -> &[u8] {
+        self.mmap.as_ref()
+    }
+
+    /// Returns the input as a string slice, failing if it is not valid UTF-8
+    pub fn as_str(&self) -> Result<&str, Box<dyn Error>> {
+        std::str::from_utf8(self.as_bytes()).map_err(|e| format!("{}: {e}", self.name).into())
+    }
+
+    /// Returns an iterator over the lines of the input without copying
+    pub fn str_lines(&self) -> Result<std::str::Lines<'_>, Box<dyn Error>> {
+        Ok(self.as_str()?.lines())
+    }
+
+    /// Returns an iterator over the lines of the input as byte slices without copying
+    pub fn byte_lines(&self) -> ByteLines<'_> {
+        ByteLines::new(self.as_bytes())
+    }
+
+    /// Parses the input to a vector with a transform taking borrowed lines.
+    /// The transform output may borrow from the input
+    pub fn parse_str_vec<'a, T, F>(&'a self, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        F: FnMut(&'a str) -> T,
+    {
+        Ok(self.str_lines()?.map(tfn).collect())
+    }
+
+    /// Parses the input to a vector with a transform taking borrowed byte lines.
+    /// The transform output may borrow from the input
+    pub fn parse_byte_vec<'a, T, F>(&'a self, tfn: F) -> Vec<T>
+    where
+        F: FnMut(&'a [u8]) -> T,
+    {
+        self.byte_lines().map(tfn).collect()
+    }
+
+    /// Parses the input split in to blank line separated sections with a given transform.
+    /// The transform output may borrow from the input
+    pub fn parse_sections<'a, T, F>(&'a self, tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+    where
+        F: FnMut(&[&'a str]) -> T,
+    {
+        let lines = self.str_lines()?.collect::<Vec<_>>();
+
+        Ok(sections::parse_sections(&lines, tfn))
+    }
+
+    /// Parses the input consisting of a header section, a blank line and a body with given
+    /// transforms. The transform outputs may borrow from the input
+    pub fn parse_header_body<'a, H, B, FH, FB>(
+        &'a self,
+        hfn: FH,
+        bfn: FB,
+    ) -> Result<(H, B), Box<dyn Error>>
+    where
+        FH: FnOnce(&[&'a str]) -> H,
+        FB: FnOnce(&[&'a str]) -> B,
+    {
+        let lines = self.str_lines()?.collect::<Vec<_>>();
+
+        sections::parse_header_body(&lines, hfn, bfn)
+            .map_err(|e| format!("{}: {e}", self.name).into())
+    }
+
+    /// Parses the input consisting of a header section, a blank line and a body with given
+    /// fallible transforms, reporting all lines in error. The transform outputs may borrow
+    /// from the input
+    pub fn try_parse_header_body<'a, H, B, FH, FB>(
+        &'a self,
+        hfn: FH,
+        bfn: FB,
+    ) -> Result<(H, B), Box<dyn Error>>
+    where
+        FH: FnOnce(&[&'a str]) -> Result<H, LinesError<'a>>,
+        FB: FnOnce(&[&'a str]) -> Result<B, LinesError<'a>>,
+    {
+        sections::try_parse_header_body(&self.name, self.as_str()?, hfn, bfn)
+    }
+
+    /// Parses the whole input text with a given fallible transform, reporting all lines in
+    /// error. The transform output may borrow from the input
+    pub fn try_parse_str<'a, T, F>(&'a self, tfn: F) -> Result<T, Box<dyn Error>>
+    where
+        F: FnOnce(&'a str) -> Result<T, LinesError<'a>>,
+    {
+        let text = self.as_str()?;
+
+        tfn(text).map_err(|e| e.into_parse_error(&self.name, text).into())
+    }
+
+    fn lines(&self) -> Lines<BufReader<&[u8]>> {
+        let buf_reader = BufReader::new(self.as_bytes());
+
+        buf_reader.lines()
+    }
+}
+
+/// Parse a lines iterator to a vector with a given transform
+fn parse_buf_vec<T, F>(lines: Lines<BufReader<&[u8]>>, mut tfn: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(String) -> T,
+{
+    let mut result = Vec::new();
+
+    for l in lines {
+        let line = l?;
+
+        result.push(tfn(line));
+    }
+
+    Ok(result)
+}
+
+/// Parse the next line of a line iterator with a given transform
+fn parse_buf_line<T, F>(mut lines: Lines<BufReader<&[u8]>>, mut tfn: F) -> Result<T, Box<dyn Error>>
+where
+    F: FnMut(String) -> T,
+{
+    let line = lines.next().expect("No line found in input")?;
+
+    Ok(tfn(line))
+}
+
+/// Parse a lines iterator to a vector with a given fallible transform, collecting all errors
+fn try_parse_buf_vec<T, E, F>(
+    name: &str,
+    lines: Lines<BufReader<&[u8]>>,
+    mut tfn: F,
+) -> Result<Vec<T>, Box<dyn Error>>
+where
+    F: FnMut(&str) -> Result<T, E>,
+    E: Into<LineError>,
+{
+    let mut result = Vec::new();
+    let mut errors = ParseError::new(name);
+
+    for (i, l) in lines.enumerate() {
+        let line = l?;
+
+        match tfn(&line) {
+            Ok(item) => result.push(item),
+            Err(e) => errors.push(i + 1, &line, e.into()),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors.into())
+    }
+}
@@ -0,0 +1,393 @@
+use std::{
+    env,
+    error::Error,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use super::fetch::Fetcher;
+
+/// Environment variable overriding the input directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Puzzle year of this repository. Inputs for other years are kept in a subdirectory
+/// of the input directory named after the year
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// First year of Advent of Code
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Default minimum time between downloads
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(5);
+
+/// File in the input directory recording the time of the last download
+const LAST_FETCH_FILE: &str = ".last-fetch";
+
+/// Locates puzzle input files, downloading and caching them if a fetcher is configured
+pub struct Resolver {
+    dirs: Vec<PathBuf>,
+    year: u16,
+    user: Option<String>,
+    fetcher: Option<Box<dyn Fetcher>>,
+    rate_limit: Duration,
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Resolver {
+    /// Creates a resolver searching `$AOC_INPUT_DIR` if set, otherwise `inputs` then `../inputs`
+    pub fn new() -> Self {
+        let dirs = match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => vec![PathBuf::from(dir)],
+            _ => vec![PathBuf::from("inputs"), PathBuf::from("../inputs")],
+        };
+
+        Self {
+            dirs,
+            year: DEFAULT_YEAR,
+            user: None,
+            fetcher: None,
+            rate_limit: DEFAULT_RATE_LIMIT,
+        }
+    }
+
+    /// Searches a single input directory only
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dirs = vec![dir.into()];
+        self
+    }
+
+    /// Sets the puzzle year
+    pub fn year(mut self, year: u16) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// Selects a user's input set, stored as `dayNN-<user>.txt`
+    pub fn user(mut self, user: &str) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+
+    /// Sets the fetcher used to download inputs which are not cached
+    pub fn fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Sets the minimum time between downloads
+    pub fn rate_limit(mut self, rate_limit: Duration) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// Returns the file name of the input for a day
+    pub fn file_name(&self, day: usize) -> String {
+        match &self.user {
+            Some(user) => format!("day{day:02}-{user}.txt"),
+            None => format!("day{day:02}.txt"),
+        }
+    }
+
+    /// Returns the path of the input for a day, downloading it if it is not cached and a
+    /// fetcher is configured. Cached inputs are never downloaded again
+    pub fn resolve(&self, day: usize) -> Result<PathBuf, Box<dyn Error>> {
+        let file = self.file_name(day);
+
+        if let Some(path) = self.find(&file) {
+            return Ok(path);
+        }
+
+        match &self.fetcher {
+            Some(fetcher) => self.download(fetcher.as_ref(), day, &file),
+            None => Err(format!("Input {file} not found in {}", self.searched()).into()),
+        }
+    }
+
+    /// Returns the input sets available for a day: None for the default input if present,
+    /// followed by the name of each user input set in order
+    pub fn input_sets(&self, day: usize) -> Vec<Option<String>> {
+        let default = format!("day{day:02}.txt");
+        let prefix = format!("day{day:02}-");
+
+        let mut users = self
+            .dirs
+            .iter()
+            .filter_map(|dir| fs::read_dir(self.year_dir(dir)).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|name| {
+                let user = name.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+                (!user.is_empty()).then(|| user.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        users.sort();
+        users.dedup();
+
+        let mut sets = Vec::new();
+
+        if self.find(&default).is_some() {
+            sets.push(None);
+        }
+
+        sets.extend(users.into_iter().map(Some));
+
+        sets
+    }
+
+    /// Opens a file in the input directories, returning the file and its path
+    pub fn open(&self, file: &str) -> io::Result<(File, String)> {
+        let mut result = Err(io::Error::from(io::ErrorKind::NotFound));
+
+        for dir in &self.dirs {
+            let path = self.year_dir(dir).join(file);
+
+            result = File::open(&path).map(|f| (f, path.display().to_string()));
+
+            if result.is_ok() {
+                break;
+            }
+        }
+
+        result
+    }
+
+    /// Returns the directory for the year within an input directory
+    fn year_dir(&self, dir: &Path) -> PathBuf {
+        if self.year == DEFAULT_YEAR {
+            dir.to_path_buf()
+        } else {
+            dir.join(self.year.to_string())
+        }
+    }
+
+    /// Finds a file in the input directories
+    fn find(&self, file: &str) -> Option<PathBuf> {
+        self.dirs
+            .iter()
+            .map(|dir| self.year_dir(dir).join(file))
+            .find(|path| path.is_file())
+    }
+
+    /// Lists the searched directories for error messages
+    fn searched(&self) -> String {
+        self.dirs
+            .iter()
+            .map(|dir| self.year_dir(dir).display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Downloads an input in to the first input directory
+    fn download(
+        &self,
+        fetcher: &dyn Fetcher,
+        day: usize,
+        file: &str,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        if self.year < FIRST_YEAR {
+            Err(format!("There are no puzzles for {}", self.year))?;
+        }
+
+        // Puzzles unlock at midnight EST (05:00 UTC)
+        let unlock_secs = days_from_civil(self.year, 12, day as u32) * 86400 + 5 * 3600;
+        let unlock = UNIX_EPOCH + Duration::from_secs(u64::try_from(unlock_secs)?);
+
+        if SystemTime::now() < unlock {
+            Err(format!("Day {day} of {} is not available yet", self.year))?;
+        }
+
+        let base = self.dirs.first().ok_or("No input directory")?;
+        let dir = self.year_dir(base);
+        fs::create_dir_all(&dir)?;
+
+        self.wait_for_rate_limit(base)?;
+
+        // Record the attempt before fetching so failed requests are rate limited too
+        fs::write(
+            base.join(LAST_FETCH_FILE),
+            unix_secs(SystemTime::now()).to_string(),
+        )?;
+
+        let data = fetcher.fetch(self.year, day)?;
+
+        // Write to a temporary file first so an interrupted download is never cached
+        let path = dir.join(file);
+        let temp = dir.join(format!("{file}.tmp"));
+
+        fs::write(&temp, data)?;
+        fs::rename(&temp, &path)?;
+
+        Ok(path)
+    }
+
+    /// Sleeps until the minimum time since the last download has passed
+    fn wait_for_rate_limit(&self, base: &Path) -> Result<(), Box<dyn Error>> {
+        let last = match fs::read_to_string(base.join(LAST_FETCH_FILE)) {
+            Ok(content) => content.trim().parse::<u64>().unwrap_or(0),
+            Err(_) => return Ok(()),
+        };
+
+        let next = UNIX_EPOCH + Duration::from_secs(last) + self.rate_limit;
+
+        if let Ok(wait) = next.duration_since(SystemTime::now()) {
+            thread::sleep(wait);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns seconds since the Unix epoch
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Returns the number of days from 1970-01-01 to a date, negative for earlier dates
+// From http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: u16, month: u32, day: u32) -> i64 {
+    let y = year as i64 - (month <= 2) as i64;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::input::LocalFetcher;
+
+    /// Creates an empty temporary directory for a test
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-resolver-{}-{name}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    /// Fetcher counting calls to check inputs are not downloaded twice
+    struct CountingFetcher(LocalFetcher, &'static AtomicUsize);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, year: u16, day: usize) -> Result<Vec<u8>, Box<dyn Error>> {
+            self.1.fetch_add(1, Ordering::SeqCst);
+            self.0.fetch(year, day)
+        }
+    }
+
+    #[test]
+    fn test_fetch() {
+        static FETCHES: AtomicUsize = AtomicUsize::new(0);
+
+        let server = temp_dir("server");
+        let inputs = temp_dir("inputs");
+
+        for (year, day) in [(2023, 5), (2022, 1)] {
+            let dir = server.join(format!("{year}/day/{day}"));
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("input"), format!("{year} {day}\n")).unwrap();
+        }
+
+        let resolver = |year| {
+            Resolver::new()
+                .dir(&inputs)
+                .year(year)
+                .rate_limit(Duration::ZERO)
+                .fetcher(CountingFetcher(LocalFetcher::new(&server), &FETCHES))
+        };
+
+        // Downloaded and cached
+        let path = resolver(2023).resolve(5).unwrap();
+        assert_eq!(path, inputs.join("day05.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2023 5\n");
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+        // Never fetched again
+        assert_eq!(resolver(2023).resolve(5).unwrap(), path);
+        assert_eq!(FETCHES.load(Ordering::SeqCst), 1);
+
+        // Other years go in a subdirectory
+        let path = resolver(2022).resolve(1).unwrap();
+        assert_eq!(path, inputs.join("2022").join("day01.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2022 1\n");
+
+        // Missing from the server, but still counts towards the rate limit
+        fs::remove_file(inputs.join(LAST_FETCH_FILE)).unwrap();
+        assert!(resolver(2023).resolve(6).is_err());
+        assert!(!inputs.join("day06.txt").exists());
+        assert!(inputs.join(LAST_FETCH_FILE).exists());
+
+        // Not unlocked yet
+        let error = resolver(9999).resolve(1).unwrap_err();
+        assert_eq!(error.to_string(), "Day 1 of 9999 is not available yet");
+
+        // Before the first year
+        let error = resolver(1900).resolve(1).unwrap_err();
+        assert_eq!(error.to_string(), "There are no puzzles for 1900");
+
+        fs::remove_dir_all(server).unwrap();
+        fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn test_users() {
+        let inputs = temp_dir("users");
+
+        for file in [
+            "day03-alice.txt",
+            "day03-bob.txt",
+            "day03.txt",
+            "day04-carol.txt",
+        ] {
+            fs::write(inputs.join(file), "\n").unwrap();
+        }
+
+        let resolver = Resolver::new().dir(&inputs);
+        assert_eq!(
+            resolver.input_sets(3),
+            vec![None, Some("alice".to_string()), Some("bob".to_string())]
+        );
+        assert_eq!(resolver.input_sets(4), vec![Some("carol".to_string())]);
+        assert!(resolver.input_sets(5).is_empty());
+
+        let resolver = Resolver::new().dir(&inputs).user("alice");
+        assert_eq!(resolver.file_name(3), "day03-alice.txt");
+        assert_eq!(resolver.resolve(3).unwrap(), inputs.join("day03-alice.txt"));
+
+        // No fetcher
+        let error = Resolver::new().dir(&inputs).resolve(4).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Input day04.txt not found in {}", inputs.display())
+        );
+
+        fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(2023, 12, 1), 19692);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(1900, 12, 1), -25233);
+    }
+}
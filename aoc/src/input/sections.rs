@@ -0,0 +1,162 @@
+use std::error::Error;
+
+use super::{LineError, LinesError};
+
+/// Splits lines in to sections separated by one or more blank lines.
+/// Leading and trailing blank lines are ignored
+pub fn sections<'a, 'b>(lines: &'b [&'a str]) -> impl Iterator<Item = &'b [&'a str]> {
+    lines
+        .split(|line| line.is_empty())
+        .filter(|section| !section.is_empty())
+}
+
+/// Parses lines to a vector of sections with a given transform
+pub(crate) fn parse_sections<'a, T, F>(lines: &[&'a str], tfn: F) -> Vec<T>
+where
+    F: FnMut(&[&'a str]) -> T,
+{
+    sections(lines).map(tfn).collect()
+}
+
+/// Parses lines consisting of a header section, a blank line and a body with given transforms.
+/// The body may itself contain further blank line separated sections
+pub(crate) fn parse_header_body<'a, H, B, FH, FB>(
+    lines: &[&'a str],
+    hfn: FH,
+    bfn: FB,
+) -> Result<(H, B), Box<dyn Error>>
+where
+    FH: FnOnce(&[&'a str]) -> H,
+    FB: FnOnce(&[&'a str]) -> B,
+{
+    // Skip leading blank lines
+    let start = lines
+        .iter()
+        .position(|line| !line.is_empty())
+        .ok_or("Input is empty")?;
+
+    let lines = &lines[start..];
+
+    let split = lines
+        .iter()
+        .position(|line| line.is_empty())
+        .ok_or("No blank line found between header and body")?;
+
+    // Skip blank lines before the body
+    let (header, body) = lines.split_at(split);
+    let body_start = body
+        .iter()
+        .position(|line| !line.is_empty())
+        .unwrap_or(body.len());
+
+    Ok((hfn(header), bfn(&body[body_start..])))
+}
+
+/// Maps lines with a fallible transform, adding any errors to an error list.
+/// Lines which fail to parse are left out of the result
+pub fn try_map_lines<'a, T, E, F>(
+    lines: &[&'a str],
+    errors: &mut LinesError<'a>,
+    mut tfn: F,
+) -> Vec<T>
+where
+    F: FnMut(&'a str) -> Result<T, E>,
+    E: Into<LineError>,
+{
+    lines
+        .iter()
+        .filter_map(|&line| match tfn(line) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                errors.push(line, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Parses source text consisting of a header section, a blank line and a body with given
+/// fallible transforms, reporting the lines in error from both transforms
+pub(crate) fn try_parse_header_body<'a, H, B, FH, FB>(
+    source: &str,
+    text: &'a str,
+    hfn: FH,
+    bfn: FB,
+) -> Result<(H, B), Box<dyn Error>>
+where
+    FH: FnOnce(&[&'a str]) -> Result<H, LinesError<'a>>,
+    FB: FnOnce(&[&'a str]) -> Result<B, LinesError<'a>>,
+{
+    let lines = text.lines().collect::<Vec<_>>();
+
+    match parse_header_body(&lines, hfn, bfn).map_err(|e| format!("{source}: {e}"))? {
+        (Ok(header), Ok(body)) => Ok((header, body)),
+        (header, body) => {
+            let mut errors = LinesError::new();
+
+            for e in [header.err(), body.err()].into_iter().flatten() {
+                errors.append(e);
+            }
+
+            Err(errors.into_parse_error(source, text).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let lines = ["", "a", "b", "", "", "c", ""];
+
+        assert_eq!(
+            sections(&lines).collect::<Vec<_>>(),
+            vec![&["a", "b"][..], &["c"][..]]
+        );
+    }
+
+    #[test]
+    fn test_header_body() {
+        let lines = ["h", "", "a", "", "b", "c"];
+
+        let (header, body) =
+            parse_header_body(&lines, |h| h.to_vec(), |b| sections(b).count()).unwrap();
+
+        assert_eq!(header, vec!["h"]);
+        assert_eq!(body, 2);
+
+        assert!(parse_header_body(&["h"], |_| (), |_| ()).is_err());
+    }
+
+    #[test]
+    fn test_try_header_body() {
+        let parse_nums = |lines: &[&'static str]| {
+            let mut errors = LinesError::new();
+            let nums = try_map_lines(lines, &mut errors, |l| l.parse::<u8>());
+            errors.or_value(nums)
+        };
+
+        let (header, body) =
+            try_parse_header_body("test", "1\n\n2\n3", parse_nums, parse_nums).unwrap();
+
+        assert_eq!(header, vec![1]);
+        assert_eq!(body, vec![2, 3]);
+
+        let error = try_parse_header_body("test", "x\n\n2\ny", parse_nums, parse_nums)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("--> test:1:1"));
+        assert!(error.contains("--> test:4:1"));
+        assert!(error.ends_with("2 lines failed to parse"));
+
+        assert_eq!(
+            try_parse_header_body("test", "1", parse_nums, parse_nums)
+                .unwrap_err()
+                .to_string(),
+            "test: No blank line found between header and body"
+        );
+    }
+}
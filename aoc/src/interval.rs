@@ -0,0 +1,398 @@
+use std::{
+    cmp::{max, min},
+    iter::Product,
+    ops::{Add, Range, Sub},
+};
+
+/// Set of values stored as sorted, disjoint half-open ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    /// Creates a new empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set containing a single range
+    pub fn from_range(range: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+
+    /// Returns the disjoint ranges in the set in ascending order.
+    /// Adjacent ranges are always merged
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    /// Returns true if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the lowest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// Returns true if the set contains a value
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|r| r.end <= value);
+
+        self.ranges.get(idx).is_some_and(|r| r.start <= value)
+    }
+
+    /// Returns the number of values in the set
+    pub fn size(&self) -> T
+    where
+        T: Default + Add<Output = T> + Sub<Output = T>,
+    {
+        self.ranges
+            .iter()
+            .fold(T::default(), |acc, r| acc + (r.end - r.start))
+    }
+
+    /// Adds a range to the set, merging with any overlapping or adjacent ranges
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Ranges entirely before and after the new range are kept
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merging = &self.ranges[first..last];
+
+        let merged = match (merging.first(), merging.last()) {
+            (Some(lo), Some(hi)) => min(lo.start, range.start)..max(hi.end, range.end),
+            _ => range,
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Returns the union of two sets
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+
+        for r in &other.ranges {
+            result.insert(r.clone());
+        }
+
+        result
+    }
+
+    /// Returns the values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+
+            let start = max(a.start, b.start);
+            let end = min(a.end, b.end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // Move past whichever range finishes first
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Returns the values in this set which are not in the other set
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for a in &self.ranges {
+            let mut start = a.start;
+
+            // Skip ranges finishing before this one
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+
+            // Cut out each overlapping range
+            let mut k = j;
+
+            while k < other.ranges.len() && other.ranges[k].start < a.end {
+                let b = &other.ranges[k];
+
+                if b.start > start {
+                    ranges.push(start..b.start);
+                }
+
+                start = max(start, b.end);
+                k += 1;
+            }
+
+            if start < a.end {
+                ranges.push(start..a.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Splits the set into values less than `at` and values greater than or equal to `at`
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let idx = self.ranges.partition_point(|r| r.end <= at);
+
+        let mut below = self.ranges[..idx].to_vec();
+        let mut above = self.ranges[idx..].to_vec();
+
+        // Split a range straddling the split point
+        if let Some(r) = above.first_mut() {
+            if r.start < at {
+                below.push(r.start..at);
+                r.start = at;
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Maps values through a list of (source range, destination start) translations.
+    /// Values not covered by a source range are unchanged. Source ranges must not overlap
+    pub fn translate(&self, translations: impl IntoIterator<Item = (Range<T>, T)>) -> Self
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        let mut unmapped = self.clone();
+        let mut result = Self::new();
+
+        for (source, dest) in translations {
+            let start = source.start;
+            let source = Self::from_range(source);
+
+            for r in self.intersection(&source).ranges {
+                result.insert((r.start - start + dest)..(r.end - start + dest));
+            }
+
+            unmapped = unmapped.difference(&source);
+        }
+
+        result.union(&unmapped)
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+/// N-dimensional box made of a half-open range on each axis
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalBox<T, const N: usize> {
+    axes: [Range<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> IntervalBox<T, N> {
+    /// Creates a new box from a range on each axis
+    pub fn new(axes: [Range<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    /// Returns the range on an axis
+    pub fn axis(&self, axis: usize) -> &Range<T> {
+        &self.axes[axis]
+    }
+
+    /// Returns true if the box contains no points
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|r| r.is_empty())
+    }
+
+    /// Returns true if the box contains a point
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(r, p)| r.contains(p))
+    }
+
+    /// Returns the number of points in the box
+    pub fn volume<V>(&self) -> V
+    where
+        T: Sub<Output = T>,
+        V: From<T> + Product,
+    {
+        self.axes
+            .iter()
+            .map(|r| V::from(max(r.start, r.end) - r.start))
+            .product()
+    }
+
+    /// Returns the box covered by both boxes, which may be empty
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut axes = self.axes.clone();
+
+        for (a, b) in axes.iter_mut().zip(&other.axes) {
+            let start = max(a.start, b.start);
+            *a = start..max(start, min(a.end, b.end));
+        }
+
+        Self { axes }
+    }
+
+    /// Splits the box on an axis into the parts with values less than `at` and
+    /// greater than or equal to `at`. Either part may be empty
+    pub fn split_at(&self, axis: usize, at: T) -> (Self, Self) {
+        let range = &self.axes[axis];
+        let mid = at.clamp(range.start, max(range.start, range.end));
+
+        let mut lower = self.clone();
+        let mut upper = self.clone();
+
+        lower.axes[axis] = range.start..mid;
+        upper.axes[axis] = mid..range.end;
+
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    /// Builds a pseudo random set of ranges within 0..64
+    fn random_set(seed: &mut u64) -> IntervalSet<u8> {
+        let mut next = || {
+            *seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (*seed >> 58) as u8
+        };
+
+        (0..next() % 6)
+            .map(|_| {
+                let start = next();
+                start..start + next() % 12
+            })
+            .collect()
+    }
+
+    fn values(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.ranges().iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn assert_normalised(set: &IntervalSet<u8>) {
+        for r in set.ranges() {
+            assert!(!r.is_empty());
+        }
+
+        for pair in set.ranges().windows(2) {
+            assert!(pair[0].end < pair[1].start);
+        }
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let mut seed = 1;
+
+        for _ in 0..500 {
+            let a = random_set(&mut seed);
+            let b = random_set(&mut seed);
+            let (va, vb) = (values(&a), values(&b));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let difference = a.difference(&b);
+
+            for set in [&a, &b, &union, &intersection, &difference] {
+                assert_normalised(set);
+            }
+
+            assert_eq!(values(&union), &va | &vb);
+            assert_eq!(values(&intersection), &va & &vb);
+            assert_eq!(values(&difference), &va - &vb);
+            assert_eq!(a.size() as usize, va.len());
+
+            for v in 0..80 {
+                assert_eq!(a.contains(v), va.contains(&v));
+            }
+
+            let (below, above) = a.split_at(20);
+            assert!(values(&below).iter().all(|&v| v < 20));
+            assert!(values(&above).iter().all(|&v| v >= 20));
+            assert_eq!(below.union(&above), a);
+        }
+    }
+
+    #[test]
+    fn test_insert() {
+        let set: IntervalSet<u8> = [5..8, 1..3, 3..4, 10..10, 7..9].into_iter().collect();
+
+        assert_eq!(set.ranges(), &[1..4, 5..9]);
+        assert_eq!(set.min(), Some(1));
+        assert_eq!(IntervalSet::<u8>::new().min(), None);
+    }
+
+    #[test]
+    fn test_translate() {
+        let set = IntervalSet::from_iter([0u64..10, 20..30]);
+
+        // 5..25 -> 105..125
+        let mapped = set.translate([(5..25, 105)]);
+
+        assert_eq!(mapped.ranges(), &[0..5, 25..30, 105..110, 120..125]);
+        assert_eq!(mapped.size(), set.size());
+    }
+
+    #[test]
+    fn test_box() {
+        let b = IntervalBox::new([0u16..10, 5..15, 2..4]);
+
+        assert_eq!(b.volume::<u64>(), 200);
+        assert!(b.contains(&[9, 5, 3]));
+        assert!(!b.contains(&[9, 15, 3]));
+
+        let (lower, upper) = b.split_at(1, 8);
+        assert_eq!(lower.axis(1), &(5..8));
+        assert_eq!(upper.axis(1), &(8..15));
+        assert_eq!(
+            lower.volume::<u64>() + upper.volume::<u64>(),
+            b.volume::<u64>()
+        );
+
+        let (lower, upper) = b.split_at(0, 20);
+        assert_eq!(lower, b);
+        assert!(upper.is_empty());
+        assert_eq!(upper.volume::<u64>(), 0);
+
+        let other = IntervalBox::new([5u16..20, 0..6, 0..3]);
+        assert_eq!(
+            b.intersection(&other),
+            IntervalBox::new([5..10, 5..6, 2..3])
+        );
+        assert!(b
+            .intersection(&IntervalBox::new([20..30, 0..1, 0..1]))
+            .is_empty());
+    }
+}
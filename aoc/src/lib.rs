@@ -1,2 +1,12 @@
+pub mod cycle;
+pub mod examples;
+pub mod geom;
 pub mod gif;
+pub mod grid;
 pub mod input;
+pub mod interval;
+pub mod math;
+pub mod polygon;
+pub mod search;
+pub mod solution;
+pub mod timing;
@@ -0,0 +1,429 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// Error from a number theory calculation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    /// The result does not fit in the result type
+    Overflow(&'static str),
+    /// A system of congruences has no solution
+    NoSolution,
+    /// The result is not an integer
+    NotInteger(&'static str),
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow(op) => write!(f, "Overflow calculating {op}"),
+            MathError::NoSolution => write!(f, "Congruences have no solution"),
+            MathError::NotInteger(op) => write!(f, "Result of {op} is not an integer"),
+        }
+    }
+}
+
+impl Error for MathError {}
+
+/// Greatest common divisor, using the binary GCD algorithm.
+/// gcd(0, n) is n
+// From https://en.wikipedia.org/wiki/Binary_GCD_algorithm
+pub fn gcd(mut u: u64, mut v: u64) -> u64 {
+    if u == 0 || v == 0 {
+        return u | v;
+    }
+
+    // Power of two common to both numbers
+    let shift = (u | v).trailing_zeros();
+
+    // Remove factors of two to make both numbers odd
+    u >>= u.trailing_zeros();
+    v >>= v.trailing_zeros();
+
+    while u != v {
+        if u < v {
+            std::mem::swap(&mut u, &mut v);
+        }
+
+        // Difference of two odd numbers is even
+        u -= v;
+        u >>= u.trailing_zeros();
+    }
+
+    u << shift
+}
+
+/// Lowest common multiple. Panics on overflow, see [`checked_lcm`].
+/// lcm(0, n) is 0
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).unwrap()
+}
+
+/// Lowest common multiple, returning an error on overflow
+pub fn checked_lcm(a: u64, b: u64) -> Result<u64, MathError> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    // Divide first to keep the intermediate value small
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .ok_or(MathError::Overflow("lcm"))
+}
+
+/// Greatest common divisor of all numbers. Returns 0 for no numbers
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// Lowest common multiple of all numbers, returning an error on overflow.
+/// Returns 1 for no numbers
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Result<u64, MathError> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Extended Euclidean algorithm. Returns (g, x, y) where g = gcd(a, b) >= 0 and a * x + b * y = g.
+/// Returns an error if g does not fit in an i64, which happens when g = 2^63
+pub fn extended_gcd(a: i64, b: i64) -> Result<(i64, i64, i64), MathError> {
+    let (g, x, y) = extended_gcd_i128(a as i128, b as i128);
+
+    let fit = |n: i128| i64::try_from(n).map_err(|_| MathError::Overflow("extended gcd"));
+
+    Ok((fit(g)?, fit(x)?, fit(y)?))
+}
+
+/// Extended Euclidean algorithm over 128 bit integers
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Modular multiplicative inverse of a modulo m, in the range 0..m.
+/// Returns None if a and m are not coprime. Panics if m is not positive
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "Modulus must be positive");
+
+    let (g, x, _) = extended_gcd_i128(a.rem_euclid(m) as i128, m as i128);
+
+    (g == 1).then(|| x.rem_euclid(m as i128) as i64)
+}
+
+/// Solves a system of congruences x ≡ residue (mod modulus) using the Chinese remainder
+/// theorem. The moduli need not be coprime. Returns (x, m) where x is the smallest
+/// non-negative solution and all solutions are x + k * m. Panics if a modulus is not positive
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Result<(i64, i64), MathError> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "Modulus must be positive");
+
+            let r2 = r2.rem_euclid(m2);
+
+            // m1 * p + m2 * q = g
+            let (g, p, _) = extended_gcd_i128(m1 as i128, m2 as i128);
+            let diff = r2 as i128 - r1 as i128;
+
+            if diff % g != 0 {
+                return Err(MathError::NoSolution);
+            }
+
+            let m: i64 = (m1 as i128 / g * m2 as i128)
+                .try_into()
+                .map_err(|_| MathError::Overflow("crt"))?;
+
+            // x = r1 + m1 * k where k ≡ diff / g * p (mod m2 / g)
+            let k = (diff / g * p).rem_euclid(m2 as i128 / g);
+            let x = (r1 as i128 + m1 as i128 * k).rem_euclid(m as i128);
+
+            Ok((x as i64, m))
+        })
+}
+
+/// Integer square root, rounded down
+pub fn isqrt(n: u64) -> u64 {
+    n.isqrt()
+}
+
+/// Returns the square root of n if n is a perfect square
+pub fn exact_sqrt(n: u64) -> Option<u64> {
+    let root = n.isqrt();
+
+    (root * root == n).then_some(root)
+}
+
+/// Quadratic a x^2 + b x + c with integer coefficients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quadratic {
+    pub a: i64,
+    pub b: i64,
+    pub c: i64,
+}
+
+impl Quadratic {
+    /// Finds the quadratic through f(0), f(1) and f(2).
+    /// Returns an error if the coefficients are not integers or overflow
+    pub fn through(f0: i64, f1: i64, f2: i64) -> Result<Self, MathError> {
+        let overflow = MathError::Overflow("quadratic");
+
+        // Second difference is 2a
+        let a2 = f1
+            .checked_mul(2)
+            .and_then(|f1x2| f2.checked_sub(f1x2))
+            .and_then(|v| v.checked_add(f0))
+            .ok_or(overflow)?;
+
+        if a2 % 2 != 0 {
+            return Err(MathError::NotInteger("quadratic"));
+        }
+
+        let a = a2 / 2;
+        let c = f0;
+        let b = f1
+            .checked_sub(a)
+            .and_then(|v| v.checked_sub(c))
+            .ok_or(overflow)?;
+
+        Ok(Self { a, b, c })
+    }
+
+    /// Evaluates the quadratic at x, returning an error on overflow
+    pub fn eval(&self, x: i64) -> Result<i64, MathError> {
+        // Horner's method: (a x + b) x + c
+        self.a
+            .checked_mul(x)
+            .and_then(|v| v.checked_add(self.b))
+            .and_then(|v| v.checked_mul(x))
+            .and_then(|v| v.checked_add(self.c))
+            .ok_or(MathError::Overflow("quadratic"))
+    }
+}
+
+impl Display for Quadratic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} x^2 + {} x + {}", self.a, self.b, self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Simple linear congruential generator for repeatable pseudo random tests
+    fn numbers(seed: u64, count: usize) -> impl Iterator<Item = u64> {
+        (0..count).scan(seed, |state, _| {
+            *state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            Some(*state >> 33)
+        })
+    }
+
+    fn slow_gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            slow_gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        for a in 0..100 {
+            for b in 0..100 {
+                let g = gcd(a, b);
+
+                assert_eq!(g, slow_gcd(a, b));
+                assert_eq!(g, gcd(b, a));
+
+                if g != 0 {
+                    assert_eq!(a % g, 0);
+                    assert_eq!(b % g, 0);
+                    assert_eq!(lcm(a, b) * g, a * b);
+                }
+            }
+        }
+
+        let values = numbers(1, 200).collect::<Vec<_>>();
+
+        for pair in values.chunks(2) {
+            assert_eq!(gcd(pair[0], pair[1]), slow_gcd(pair[0], pair[1]));
+        }
+
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Ok(60));
+        assert_eq!(lcm_all([]), Ok(1));
+        assert_eq!(
+            lcm_all([u64::MAX, u64::MAX - 1]),
+            Err(MathError::Overflow("lcm"))
+        );
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for a in -50..50 {
+            for b in -50..50 {
+                let (g, x, y) = extended_gcd(a, b).unwrap();
+
+                assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+                assert_eq!(a * x + b * y, g);
+            }
+        }
+
+        for m in 1..60 {
+            for a in -60..60 {
+                match mod_inverse(a, m) {
+                    Some(inv) => {
+                        assert!((0..m).contains(&inv));
+                        assert_eq!((a * inv).rem_euclid(m), 1 % m);
+                    }
+                    None => assert_ne!(gcd(a.unsigned_abs(), m as u64), 1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extended_gcd_extremes() {
+        // Full range values including the extremes, with some sharing large factors
+        let wide = numbers(3, 200)
+            .zip(numbers(4, 200))
+            .map(|(hi, lo)| ((hi << 33) ^ lo) as i64);
+        let extremes = [
+            i64::MIN,
+            i64::MIN + 1,
+            -(1 << 62),
+            -1,
+            0,
+            1,
+            1 << 62,
+            i64::MAX,
+        ];
+
+        let values = wide
+            .chain(extremes)
+            .flat_map(|n| [n, n & !0xffff, n / 3 * 3])
+            .collect::<Vec<_>>();
+
+        for &a in &values {
+            for &b in &values {
+                let g = gcd(a.unsigned_abs(), b.unsigned_abs());
+
+                match extended_gcd(a, b) {
+                    Ok((eg, x, y)) => {
+                        assert_eq!(eg as u64, g, "{a} {b}");
+                        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, eg as i128);
+                    }
+                    Err(e) => {
+                        assert_eq!(e, MathError::Overflow("extended gcd"));
+                        assert_eq!(g, 1 << 63, "{a} {b}");
+                    }
+                }
+            }
+        }
+
+        assert_eq!(extended_gcd(i64::MIN, 1), Ok((1, 0, 1)));
+        assert!(extended_gcd(i64::MIN, 0).is_err());
+        assert!(extended_gcd(i64::MIN, i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_crt() {
+        // Brute force all pairs of small congruences
+        for m1 in 1..13 {
+            for m2 in 1..13 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let m = m1 * m2 / gcd(m1 as u64, m2 as u64) as i64;
+                        let expected = (0..m).find(|x| x % m1 == r1 && x % m2 == r2);
+
+                        match crt([(r1, m1), (r2, m2)]) {
+                            Ok(result) => assert_eq!(Some(result), expected.map(|x| (x, m))),
+                            Err(e) => {
+                                assert_eq!(e, MathError::NoSolution);
+                                assert_eq!(expected, None);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt([(-1, 4), (5, 6)]), Ok((11, 12)));
+        assert_eq!(crt([]), Ok((0, 1)));
+        assert_eq!(
+            crt([(0, i64::MAX), (0, i64::MAX - 1)]),
+            Err(MathError::Overflow("crt"))
+        );
+    }
+
+    #[test]
+    fn test_sqrt() {
+        for n in 0..10_000 {
+            let root = isqrt(n);
+
+            assert!(root * root <= n);
+            assert!((root + 1) * (root + 1) > n);
+            assert_eq!(exact_sqrt(n).is_some(), root * root == n);
+        }
+
+        for n in numbers(2, 1000) {
+            let square = n * n;
+
+            assert_eq!(isqrt(square), n);
+            assert_eq!(exact_sqrt(square), Some(n));
+            assert_eq!(isqrt(square + 1), n);
+            assert_eq!(exact_sqrt(square + 1), None);
+            assert_eq!(isqrt(square - 1), n - 1);
+        }
+
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_quadratic() {
+        let f = |x: i64| 3 * x * x - 7 * x + 11;
+        let quad = Quadratic::through(f(0), f(1), f(2)).unwrap();
+
+        assert_eq!(quad, Quadratic { a: 3, b: -7, c: 11 });
+
+        for x in -100..100 {
+            assert_eq!(quad.eval(x), Ok(f(x)));
+        }
+
+        assert_eq!(
+            Quadratic::through(0, 0, 1),
+            Err(MathError::NotInteger("quadratic"))
+        );
+        assert_eq!(
+            Quadratic::through(0, i64::MIN, 0),
+            Err(MathError::Overflow("quadratic"))
+        );
+        assert_eq!(
+            Quadratic::through(i64::MAX, 0, i64::MAX),
+            Err(MathError::Overflow("quadratic"))
+        );
+        assert_eq!(
+            Quadratic::through(i64::MIN, 0, 2),
+            Err(MathError::Overflow("quadratic"))
+        );
+        assert_eq!(quad.eval(i64::MAX), Err(MathError::Overflow("quadratic")));
+    }
+}
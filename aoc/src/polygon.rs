@@ -0,0 +1,215 @@
+use crate::{geom::Vec2, math::gcd};
+
+/// Location of a point relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Builds the vertex list of a polygon by applying each move in turn from a start point.
+/// The final move should return to the start, which is not repeated in the list
+pub fn trace(start: Vec2, moves: impl IntoIterator<Item = Vec2>) -> Vec<Vec2> {
+    let mut vertices = vec![start];
+
+    for m in moves {
+        let next = *vertices.last().unwrap() + m;
+        vertices.push(next);
+    }
+
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+
+    vertices
+}
+
+/// Returns the edges of a polygon as (from, to) vertex pairs, including the closing edge
+fn edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Returns twice the signed area of a polygon using the shoelace formula.
+/// The area is positive if the vertices run clockwise with y increasing downwards
+pub fn double_area(vertices: &[Vec2]) -> i128 {
+    edges(vertices)
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum()
+}
+
+/// Returns twice the signed area of a polygon using 64 bit arithmetic, or None on overflow
+pub fn checked_double_area(vertices: &[Vec2]) -> Option<i64> {
+    edges(vertices).try_fold(0i64, |acc, (a, b)| {
+        acc.checked_add(a.x.checked_mul(b.y)?.checked_sub(b.x.checked_mul(a.y)?)?)
+    })
+}
+
+/// Returns the number of lattice points on the boundary of a polygon with integer vertices
+pub fn boundary_points(vertices: &[Vec2]) -> u64 {
+    edges(vertices)
+        .map(|(a, b)| {
+            let d = b - a;
+            gcd(d.x.unsigned_abs(), d.y.unsigned_abs())
+        })
+        .sum()
+}
+
+/// Returns the number of lattice points strictly inside a polygon with integer vertices,
+/// using Pick's theorem: A = I + B / 2 - 1
+pub fn interior_points(vertices: &[Vec2]) -> i128 {
+    (double_area(vertices).abs() - boundary_points(vertices) as i128 + 2) / 2
+}
+
+/// Returns the number of lattice points inside or on the boundary of a polygon with
+/// integer vertices
+pub fn lattice_points(vertices: &[Vec2]) -> i128 {
+    interior_points(vertices) + boundary_points(vertices) as i128
+}
+
+/// Finds whether a point is inside, outside or on the boundary of a polygon
+pub fn locate(vertices: &[Vec2], point: Vec2) -> Location {
+    let mut inside = false;
+
+    for (a, b) in edges(vertices) {
+        let edge = b - a;
+        let rel = point - a;
+
+        // Cross product is zero if the point is on the line through the edge
+        let cross = edge.x as i128 * rel.y as i128 - edge.y as i128 * rel.x as i128;
+
+        if cross == 0
+            && point.x >= a.x.min(b.x)
+            && point.x <= a.x.max(b.x)
+            && point.y >= a.y.min(b.y)
+            && point.y <= a.y.max(b.y)
+        {
+            return Location::Boundary;
+        }
+
+        // Count edges crossing a ray cast in the +x direction. Edges include their lower
+        // end point only so vertices on the ray are counted once
+        if (a.y > point.y) != (b.y > point.y) {
+            // Point is left of the edge if the cross product has the same sign as dy
+            if (cross > 0) == (edge.y > 0) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the lattice point counts against locating every point in the bounding box
+    fn check_counts(vertices: &[Vec2]) {
+        let (mut interior, mut boundary) = (0, 0);
+
+        let min_x = vertices.iter().map(|v| v.x).min().unwrap();
+        let max_x = vertices.iter().map(|v| v.x).max().unwrap();
+        let min_y = vertices.iter().map(|v| v.y).min().unwrap();
+        let max_y = vertices.iter().map(|v| v.y).max().unwrap();
+
+        for y in min_y - 1..=max_y + 1 {
+            for x in min_x - 1..=max_x + 1 {
+                match locate(vertices, Vec2::new(x, y)) {
+                    Location::Inside => interior += 1,
+                    Location::Boundary => boundary += 1,
+                    Location::Outside => (),
+                }
+            }
+        }
+
+        assert_eq!(interior_points(vertices), interior);
+        assert_eq!(boundary_points(vertices), boundary);
+        assert_eq!(lattice_points(vertices), interior + boundary as i128);
+    }
+
+    #[test]
+    fn test_square() {
+        let square = trace(
+            Vec2::new(0, 0),
+            [(4, 0), (0, 4), (-4, 0), (0, -4)].map(|(x, y)| Vec2::new(x, y)),
+        );
+
+        assert_eq!(square.len(), 4);
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(checked_double_area(&square), Some(32));
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+        assert_eq!(lattice_points(&square), 25);
+
+        // Anticlockwise
+        let reversed = square.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(double_area(&reversed), -32);
+        assert_eq!(interior_points(&reversed), 9);
+
+        assert_eq!(locate(&square, Vec2::new(2, 2)), Location::Inside);
+        assert_eq!(locate(&square, Vec2::new(4, 2)), Location::Boundary);
+        assert_eq!(locate(&square, Vec2::new(0, 0)), Location::Boundary);
+        assert_eq!(locate(&square, Vec2::new(5, 0)), Location::Outside);
+        assert_eq!(locate(&square, Vec2::new(-1, 4)), Location::Outside);
+
+        check_counts(&square);
+    }
+
+    #[test]
+    fn test_shapes() {
+        // Concave rectilinear shape
+        check_counts(&trace(
+            Vec2::new(0, 0),
+            [
+                (6, 0),
+                (0, 5),
+                (-2, 0),
+                (0, 2),
+                (2, 0),
+                (0, 2),
+                (-5, 0),
+                (0, -2),
+                (-1, 0),
+                (0, -2),
+                (2, 0),
+                (0, -3),
+                (-2, 0),
+            ]
+            .map(|(x, y)| Vec2::new(x, y)),
+        ));
+
+        // Triangle with diagonal edges
+        check_counts(&[Vec2::new(0, 0), Vec2::new(7, 3), Vec2::new(2, 9)]);
+
+        // Spiky shape with vertices on the ray
+        check_counts(&[
+            Vec2::new(0, 0),
+            Vec2::new(3, 3),
+            Vec2::new(6, 0),
+            Vec2::new(9, 3),
+            Vec2::new(9, 6),
+            Vec2::new(0, 6),
+        ]);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = [
+            Vec2::new(0, 0),
+            Vec2::new(i64::MAX, 0),
+            Vec2::new(i64::MAX, i64::MAX),
+            Vec2::new(0, i64::MAX),
+        ];
+
+        assert_eq!(checked_double_area(&big), None);
+        assert_eq!(double_area(&big), 2 * i64::MAX as i128 * i64::MAX as i128);
+    }
+}
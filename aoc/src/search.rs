@@ -0,0 +1,384 @@
+use std::{
+    cmp::{max, Ordering},
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Statistics gathered during a search
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Number of nodes taken from the queue and expanded
+    pub expanded: usize,
+    /// Number of distinct nodes reached
+    pub reached: usize,
+    /// Number of nodes pushed on to the queue
+    pub pushed: usize,
+    /// Maximum length of the queue
+    pub max_queue: usize,
+}
+
+/// Result of a successful search
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C> {
+    /// Total cost from the start to the goal
+    pub cost: C,
+    /// The goal node reached
+    pub goal: N,
+    /// Path from the start to the goal inclusive, if path tracking was enabled
+    pub path: Option<Vec<N>>,
+    /// Search statistics
+    pub stats: SearchStats,
+}
+
+/// Search options
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Search {
+    track_path: bool,
+}
+
+impl Search {
+    /// Creates a new search with default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables path reconstruction
+    pub fn with_path(mut self) -> Self {
+        self.track_path = true;
+        self
+    }
+
+    /// Finds the lowest cost path from any of the start nodes to a goal node using Dijkstra's
+    /// algorithm. `successors` returns the next nodes and the cost of moving to each
+    pub fn dijkstra<N, C, FS, IS, FG>(
+        &self,
+        starts: impl IntoIterator<Item = N>,
+        successors: FS,
+        is_goal: FG,
+    ) -> Option<SearchResult<N, C>>
+    where
+        N: Eq + Hash + Clone,
+        C: Ord + Copy + Default + Add<Output = C>,
+        FS: FnMut(&N) -> IS,
+        IS: IntoIterator<Item = (N, C)>,
+        FG: FnMut(&N) -> bool,
+    {
+        self.astar(starts, successors, |_| C::default(), is_goal)
+    }
+
+    /// Finds the lowest cost path from any of the start nodes to a goal node using A*.
+    /// `heuristic` must never overestimate the remaining cost to the goal
+    pub fn astar<N, C, FS, IS, FH, FG>(
+        &self,
+        starts: impl IntoIterator<Item = N>,
+        mut successors: FS,
+        mut heuristic: FH,
+        mut is_goal: FG,
+    ) -> Option<SearchResult<N, C>>
+    where
+        N: Eq + Hash + Clone,
+        C: Ord + Copy + Default + Add<Output = C>,
+        FS: FnMut(&N) -> IS,
+        IS: IntoIterator<Item = (N, C)>,
+        FH: FnMut(&N) -> C,
+        FG: FnMut(&N) -> bool,
+    {
+        let mut stats = SearchStats::default();
+        let mut queue = BinaryHeap::new();
+        let mut best = HashMap::new();
+        let mut parents = HashMap::new();
+
+        for start in starts {
+            best.insert(start.clone(), C::default());
+
+            queue.push(QueueEnt {
+                priority: heuristic(&start),
+                cost: C::default(),
+                node: start,
+            });
+        }
+
+        stats.pushed = queue.len();
+
+        while let Some(QueueEnt { cost, node, .. }) = queue.pop() {
+            // Skip if a cheaper route to this node has been found since it was queued
+            if best.get(&node).is_some_and(|&c| c < cost) {
+                continue;
+            }
+
+            stats.expanded += 1;
+
+            if is_goal(&node) {
+                stats.reached = best.len();
+
+                let path = self.track_path.then(|| reconstruct(&parents, &node));
+
+                return Some(SearchResult {
+                    cost,
+                    goal: node,
+                    path,
+                    stats,
+                });
+            }
+
+            for (next, step_cost) in successors(&node) {
+                let next_cost = cost + step_cost;
+
+                match best.entry(next.clone()) {
+                    Entry::Occupied(mut e) => {
+                        if *e.get() <= next_cost {
+                            continue;
+                        }
+
+                        e.insert(next_cost);
+                    }
+                    Entry::Vacant(e) => {
+                        e.insert(next_cost);
+                    }
+                }
+
+                if self.track_path {
+                    parents.insert(next.clone(), node.clone());
+                }
+
+                queue.push(QueueEnt {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+
+                stats.pushed += 1;
+            }
+
+            stats.max_queue = max(stats.max_queue, queue.len());
+        }
+
+        None
+    }
+
+    /// Finds the shortest path in steps from any of the start nodes to a goal node using a
+    /// breadth first search
+    pub fn bfs<N, FS, IS, FG>(
+        &self,
+        starts: impl IntoIterator<Item = N>,
+        mut successors: FS,
+        mut is_goal: FG,
+    ) -> Option<SearchResult<N, usize>>
+    where
+        N: Eq + Hash + Clone,
+        FS: FnMut(&N) -> IS,
+        IS: IntoIterator<Item = N>,
+        FG: FnMut(&N) -> bool,
+    {
+        let mut stats = SearchStats::default();
+        let mut queue = VecDeque::new();
+        let mut steps = HashMap::new();
+        let mut parents = HashMap::new();
+
+        for start in starts {
+            if steps.insert(start.clone(), 0).is_none() {
+                queue.push_back((start, 0));
+            }
+        }
+
+        stats.pushed = queue.len();
+
+        while let Some((node, cost)) = queue.pop_front() {
+            stats.expanded += 1;
+
+            if is_goal(&node) {
+                stats.reached = steps.len();
+
+                let path = self.track_path.then(|| reconstruct(&parents, &node));
+
+                return Some(SearchResult {
+                    cost,
+                    goal: node,
+                    path,
+                    stats,
+                });
+            }
+
+            for next in successors(&node) {
+                if let Entry::Vacant(e) = steps.entry(next.clone()) {
+                    e.insert(cost + 1);
+
+                    if self.track_path {
+                        parents.insert(next.clone(), node.clone());
+                    }
+
+                    queue.push_back((next, cost + 1));
+                    stats.pushed += 1;
+                }
+            }
+
+            stats.max_queue = max(stats.max_queue, queue.len());
+        }
+
+        None
+    }
+}
+
+/// Finds the lowest cost path to a goal using Dijkstra's algorithm. See [`Search::dijkstra`]
+pub fn dijkstra<N, C, FS, IS, FG>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FS,
+    is_goal: FG,
+) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    Search::new().dijkstra(starts, successors, is_goal)
+}
+
+/// Finds the lowest cost path to a goal using A*. See [`Search::astar`]
+pub fn astar<N, C, FS, IS, FH, FG>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FS,
+    heuristic: FH,
+    is_goal: FG,
+) -> Option<SearchResult<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    Search::new().astar(starts, successors, heuristic, is_goal)
+}
+
+/// Finds the shortest path in steps to a goal using a breadth first search. See [`Search::bfs`]
+pub fn bfs<N, FS, IS, FG>(
+    starts: impl IntoIterator<Item = N>,
+    successors: FS,
+    is_goal: FG,
+) -> Option<SearchResult<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    Search::new().bfs(starts, successors, is_goal)
+}
+
+/// Walks the parent map back from a node to build the path from the start
+fn reconstruct<N: Eq + Hash + Clone>(parents: &HashMap<N, N>, end: &N) -> Vec<N> {
+    let mut path = vec![end.clone()];
+
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+
+    path
+}
+
+/// Priority queue entry, ordered so the lowest priority is popped first
+struct QueueEnt<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for QueueEnt<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for QueueEnt<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for QueueEnt<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for QueueEnt<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::parse_test_grid;
+
+    use super::*;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    #[test]
+    fn test_grid_searches() {
+        let grid = parse_test_grid(MAZE, |c| c).unwrap();
+        let start = grid.find(|&c| c == 'S').unwrap();
+        let end = grid.find(|&c| c == 'E').unwrap();
+
+        let open = |&(x, y): &(usize, usize)| {
+            grid.neighbours4(x, y)
+                .filter(|&p| grid[p] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let result = Search::new()
+            .with_path()
+            .bfs([start], open, |&p| p == end)
+            .unwrap();
+
+        assert_eq!(result.cost, 15);
+
+        let path = result.path.unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+
+        let weighted = |p: &(usize, usize)| open(p).into_iter().map(|n| (n, 1usize));
+
+        let shortest = dijkstra([start], weighted, |&p| p == end).unwrap();
+        assert_eq!(shortest.cost, 15);
+        assert_eq!(shortest.path, None);
+
+        let manhattan = |&(x, y): &(usize, usize)| end.0.abs_diff(x) + end.1.abs_diff(y);
+
+        let guided = astar([start], weighted, manhattan, |&p| p == end).unwrap();
+        assert_eq!(guided.cost, 15);
+        assert!(guided.stats.expanded <= shortest.stats.expanded);
+
+        assert!(bfs([start], open, |&p| p == (3, 0)).is_none());
+    }
+
+    #[test]
+    fn test_weighted() {
+        // a -1-> b -1-> c, a -5-> c
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1)],
+            _ => vec![],
+        };
+
+        let result = Search::new()
+            .with_path()
+            .dijkstra(['a'], edges, |&n| n == 'c')
+            .unwrap();
+
+        assert_eq!(result.cost, 2);
+        assert_eq!(result.path, Some(vec!['a', 'b', 'c']));
+    }
+}
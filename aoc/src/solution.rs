@@ -0,0 +1,105 @@
+use std::{error::Error, fmt::Display, hint::black_box};
+
+use crate::{
+    input::{Input, Resolver},
+    timing::{time, PhaseStats, Stats},
+};
+
+/// Solution for a day's puzzle
+pub trait Solution {
+    /// Day number of the puzzle
+    const DAY: usize;
+
+    /// Parsed input passed to each part
+    type Input;
+
+    /// Parses the puzzle input
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>>;
+
+    /// Calculates the answer to part 1
+    fn part1(input: &Self::Input) -> impl Display;
+
+    /// Calculates the answer to part 2
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+/// Answers for both parts of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Loads and parses the input for a solution and runs both parts
+pub fn solve<S: Solution>() -> Result<Answers, Box<dyn Error>> {
+    solve_with::<S>(&Resolver::new())
+}
+
+/// Loads and parses the input located by a resolver for a solution and runs both parts
+pub fn solve_with<S: Solution>(resolver: &Resolver) -> Result<Answers, Box<dyn Error>> {
+    // Get input
+    let input = Input::new_resolved(resolver, S::DAY)?;
+    let input = S::parse(&input)?;
+
+    // Run parts
+    let part1 = S::part1(&input).to_string();
+    let part2 = S::part2(&input).to_string();
+
+    Ok(Answers { part1, part2 })
+}
+
+/// Runs a solution a number of times with the input located by a resolver, timing input
+/// loading, parsing and each part
+pub fn bench<S: Solution>(
+    resolver: &Resolver,
+    runs: usize,
+) -> Result<(Answers, PhaseStats), Box<dyn Error>> {
+    assert!(runs > 0, "Must run at least once");
+
+    let mut answers = None;
+    let mut samples = [const { Vec::new() }; 4];
+
+    for _ in 0..runs {
+        // Get input
+        let (input, load) = time(|| Input::new_resolved(resolver, S::DAY));
+        let input = input?;
+
+        let (input, parse) = time(|| S::parse(&input));
+        let input = input?;
+
+        // Run parts
+        let (part1, part1_time) = time(|| black_box(S::part1(&input)).to_string());
+        let (part2, part2_time) = time(|| black_box(S::part2(&input)).to_string());
+
+        for (phase, sample) in samples
+            .iter_mut()
+            .zip([load, parse, part1_time, part2_time])
+        {
+            phase.push(sample);
+        }
+
+        answers = Some(Answers { part1, part2 });
+    }
+
+    let [load, parse, part1, part2] = samples.map(|s| Stats::new(&s));
+
+    Ok((
+        answers.expect("No answers"),
+        PhaseStats {
+            load,
+            parse,
+            part1,
+            part2,
+        },
+    ))
+}
+
+/// Runs a solution and prints the answers
+pub fn main<S: Solution>() -> Result<(), Box<dyn Error>> {
+    let answers = solve::<S>()?;
+
+    println!("Part 1: {}", answers.part1);
+    println!("Part 2: {}", answers.part2);
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant};
+
+/// Runs a function and returns its result along with the time taken
+pub fn time<T, F>(f: F) -> (T, Duration)
+where
+    F: FnOnce() -> T,
+{
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+/// Summary statistics for a set of timing samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Calculates statistics for a set of samples
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No timing samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+
+        let median = if sorted.len() & 0x01 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Self {
+            median,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Timing statistics for each phase of a solution run
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseStats {
+    pub load: Stats,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl PhaseStats {
+    /// Returns each phase name with its statistics
+    pub fn phases(&self) -> [(&'static str, &Stats); 4] {
+        [
+            ("load", &self.load),
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+
+    /// Returns the sum of the median times for all phases
+    pub fn total_median(&self) -> Duration {
+        self.phases().iter().map(|(_, stats)| stats.median).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        let stats = Stats::new(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.max, ms(5));
+
+        let stats = Stats::new(&[ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.max, ms(8));
+    }
+}
@@ -0,0 +1,82 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{input::Input, solution::Solution};
+
+/// Day 1 solution
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: usize = 1;
+
+    type Input = Vec<InputEnt>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_vec(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(input: &[InputEnt]) -> u64 {
+    input.iter().map(|(i, _)| *i as u64).sum()
+}
+
+fn part2(input: &[InputEnt]) -> u64 {
+    input.iter().map(|(_, i)| *i as u64).sum()
+}
+
+// Input parsing
+
+type InputEnt = (u8, u8);
+
+const NUMSTR: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn input_transform(line: String) -> InputEnt {
+    // Part 1
+    let mut iter = line.chars().filter(|c| c.is_numeric());
+    let first = iter.next().unwrap_or('0');
+    let last = iter.next_back().unwrap_or(first);
+    let p1 = ((first as u8 - b'0') * 10) + (last as u8 - b'0');
+
+    // Part 2
+    let mut iter = (0..line.len()).filter_map(|p| {
+        let sub = &line[p..];
+        let c = sub.as_bytes()[0] as char;
+
+        if c.is_numeric() {
+            Some(c as u8 - b'0')
+        } else {
+            NUMSTR
+                .iter()
+                .enumerate()
+                .filter_map(|(i, n)| {
+                    if sub.starts_with(n) {
+                        Some(i as u8)
+                    } else {
+                        None
+                    }
+                })
+                .next()
+        }
+    });
+    let first = iter.next().unwrap_or(0);
+    let last = iter.next_back().unwrap_or(first);
+    let p2 = (first * 10) + last;
+
+    (p1, p2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(Day01);
+}
@@ -0,0 +1,147 @@
+use std::{cmp::max, error::Error, fmt::Display};
+
+use aoc::{
+    input::{Input, LineError, LineResultExt},
+    solution::Solution,
+};
+
+/// Day 2 solution
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: usize = 2;
+
+    type Input = Vec<InputEnt>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.try_parse_vec(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(input: &[InputEnt]) -> u64 {
+    input.iter().fold(0, |acc, game| {
+        if game
+            .hands
+            .iter()
+            .any(|hand| hand.r > 12 || hand.g > 13 || hand.b > 14)
+        {
+            acc
+        } else {
+            acc + game.game as u64
+        }
+    })
+}
+
+fn part2(input: &[InputEnt]) -> u64 {
+    input
+        .iter()
+        .map(|game| {
+            let (r, g, b) = game.hands.iter().fold((0, 0, 0), |(r, g, b), hand| {
+                (max(r, hand.r), max(g, hand.g), max(b, hand.b))
+            });
+
+            r as u64 * g as u64 * b as u64
+        })
+        .sum()
+}
+
+pub struct Game {
+    game: u16,
+    hands: Vec<Hand>,
+}
+
+#[derive(Default)]
+struct Hand {
+    r: u16,
+    g: u16,
+    b: u16,
+}
+
+// Input parsing
+
+type InputEnt = Game;
+
+fn input_transform(line: &str) -> Result<InputEnt, LineError> {
+    let (game, hands) = line
+        .split_once(": ")
+        .ok_or_else(|| LineError::new("Expected ': ' after game number"))?;
+
+    let game_no = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| LineError::new("Expected 'Game'").within(line, game))?;
+
+    let game_no = game_no.parse::<u16>().within(line, game_no)?;
+
+    let mut game = Game {
+        game: game_no,
+        hands: Vec::new(),
+    };
+
+    for hand_str in hands.split("; ") {
+        let mut hand = Hand::default();
+
+        for cube in hand_str.split(", ") {
+            let (count, colour) = cube
+                .split_once(' ')
+                .ok_or_else(|| LineError::new("Expected count and colour").within(line, cube))?;
+
+            let count = count.parse::<u16>().within(line, count)?;
+
+            match colour {
+                "red" => hand.r = count,
+                "green" => hand.g = count,
+                "blue" => hand.b = count,
+                _ => Err(LineError::new("Invalid colour").within(line, colour))?,
+            }
+        }
+
+        game.hands.push(hand);
+    }
+
+    Ok(game)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::try_parse_test_vec;
+
+    use super::*;
+
+    aoc::example_tests!(Day02);
+
+    #[test]
+    fn test_errors() {
+        let error = try_parse_test_vec(
+            "Game 1: 3 blue\nGame 2: 4 purple\nGame 3: x red",
+            input_transform,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "\
+error: Invalid colour
+ --> <test>:2:11
+  |
+2 | Game 2: 4 purple
+  |           ^^^^^^
+
+error: invalid digit found in string
+ --> <test>:3:9
+  |
+3 | Game 3: x red
+  |         ^
+
+2 lines failed to parse"
+        );
+    }
+}
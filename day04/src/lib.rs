@@ -0,0 +1,112 @@
+use std::{collections::VecDeque, error::Error, fmt::Display};
+
+use aoc::{
+    input::{Input, LineError, LineResultExt},
+    solution::Solution,
+};
+
+/// Day 4 solution
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: usize = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.try_parse_vec(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(input: &[Card]) -> u64 {
+    input
+        .iter()
+        .map(|card| {
+            // Calclate score
+            let mut score = 0;
+
+            for n in &card.winning {
+                if card.actual.contains(n) {
+                    if score == 0 {
+                        score = 1
+                    } else {
+                        score *= 2;
+                    }
+                }
+            }
+
+            score
+        })
+        .sum()
+}
+
+fn part2(input: &[Card]) -> u64 {
+    let mut cards = 0;
+    let mut queue = VecDeque::new();
+
+    // Pre-calculate number of wins on each card
+    let wins = input
+        .iter()
+        .map(|c| c.winning.iter().filter(|w| c.actual.contains(w)).count())
+        .collect::<Vec<usize>>();
+
+    // Fill the queue with each card
+    for i in 0..input.len() {
+        queue.push_back(i);
+    }
+
+    // Process the queue
+    while let Some(ent) = queue.pop_front() {
+        cards += 1;
+
+        // Add new cards
+        for i in (0..wins[ent]).rev() {
+            queue.push_front(ent + i + 1)
+        }
+    }
+
+    cards
+}
+
+// Input parsing
+
+pub struct Card {
+    winning: Vec<u8>,
+    actual: Vec<u8>,
+}
+
+fn input_transform(line: &str) -> Result<Card, LineError> {
+    let (_, numbers) = line
+        .split_once(':')
+        .ok_or_else(|| LineError::new("Expected ':' after card number"))?;
+
+    let (winning, actual) = numbers
+        .split_once('|')
+        .ok_or_else(|| LineError::new("Expected '|' between number lists").within(line, numbers))?;
+
+    let parse_nums = |nums: &str| {
+        nums.split_whitespace()
+            .map(|n| n.parse::<u8>().within(line, n))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    Ok(Card {
+        winning: parse_nums(winning)?,
+        actual: parse_nums(actual)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(Day04);
+}
@@ -0,0 +1,164 @@
+use std::{cmp::min, error::Error, fmt::Display, ops::Range};
+
+use aoc::{
+    input::{sections, try_map_lines, Input, LineError, LineResultExt, LinesError},
+    interval::IntervalSet,
+    solution::Solution,
+};
+
+/// Day 5 solution
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: usize = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        Ok(parse_input(
+            input.try_parse_header_body(parse_seeds, parse_maps)?,
+        ))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(almanac: &Almanac) -> u64 {
+    let mut result = None;
+
+    // Iterate each seed
+    for s in &almanac.seeds {
+        let mut num = *s;
+
+        // Iterate each map
+        for m in &almanac.maps {
+            // Translate the number if it is contained in a map range
+            if let Some(e) = m.iter().find(|e| e.source.contains(&num)) {
+                num = e.dest + (num - e.source.start);
+            }
+        }
+
+        // Update result
+        result = match result {
+            Some(r) => Some(min(r, num)),
+            None => Some(num),
+        }
+    }
+
+    result.unwrap_or(0)
+}
+
+fn part2(almanac: &Almanac) -> u64 {
+    // Build the set of seed ranges
+    let seeds = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|c| c[0]..c[0] + c[1])
+        .collect::<IntervalSet<u64>>();
+
+    // Translate the set through each map in turn
+    let locations = almanac.maps.iter().fold(seeds, |set, m| {
+        set.translate(m.iter().map(|e| (e.source.clone(), e.dest)))
+    });
+
+    locations.min().unwrap_or(0)
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<ItemMap>>,
+}
+
+struct ItemMap {
+    dest: u64,
+    source: Range<u64>,
+}
+
+impl ItemMap {
+    fn new(line: &str) -> Result<Self, LineError> {
+        let nums = line
+            .split_ascii_whitespace()
+            .map(|n| n.parse::<u64>().within(line, n))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [dest, source_start, length] = nums[..] else {
+            return Err(LineError::new(format!(
+                "Expected 3 numbers in map line, found {}",
+                nums.len()
+            )));
+        };
+
+        let source_end = source_start
+            .checked_add(length)
+            .ok_or_else(|| LineError::new("Map source range overflows"))?;
+
+        Ok(ItemMap {
+            dest,
+            source: source_start..source_end,
+        })
+    }
+}
+
+// Input parsing
+
+fn parse_seeds<'a>(header: &[&'a str]) -> Result<Vec<u64>, LinesError<'a>> {
+    let mut errors = LinesError::new();
+
+    let seeds = try_map_lines(header, &mut errors, |line| {
+        let nums = line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| LineError::new("Expected 'seeds:'"))?;
+
+        nums.split_ascii_whitespace()
+            .map(|n| n.parse::<u64>().within(line, n))
+            .collect::<Result<Vec<_>, _>>()
+    });
+
+    errors.or_value(seeds.concat())
+}
+
+fn parse_maps<'a>(body: &[&'a str]) -> Result<Vec<Vec<ItemMap>>, LinesError<'a>> {
+    let mut errors = LinesError::new();
+
+    // Each section has a title line followed by the map lines
+    let maps = sections(body)
+        .map(|section| try_map_lines(&section[1..], &mut errors, ItemMap::new))
+        .collect();
+
+    errors.or_value(maps)
+}
+
+fn parse_input((seeds, maps): (Vec<u64>, Vec<Vec<ItemMap>>)) -> Almanac {
+    Almanac { seeds, maps }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::try_parse_test_header_body;
+
+    use super::*;
+
+    aoc::example_tests!(Day05);
+
+    #[test]
+    fn test_bad_input() {
+        let error = try_parse_test_header_body(
+            "seeds: 79 x\n\nseed-to-soil map:\n50 98 2\n52 50\n\nsoil map:\n0 15 37",
+            parse_seeds,
+            parse_maps,
+        )
+        .err()
+        .unwrap()
+        .to_string();
+
+        assert!(error.contains("--> <test>:1:11"));
+        assert!(error.contains("Expected 3 numbers in map line, found 2\n --> <test>:5:1"));
+        assert!(error.ends_with("2 lines failed to parse"));
+    }
+}
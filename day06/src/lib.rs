@@ -0,0 +1,186 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{
+    input::Input,
+    math::{exact_sqrt, isqrt},
+    solution::Solution,
+};
+
+/// Day 6 solution
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: usize = 6;
+
+    type Input = Races;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        parse_races(input.as_str()?)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(&input.spaced)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(&input.kerned)
+    }
+}
+
+fn part1(races: &[Race]) -> u64 {
+    races.iter().map(Race::wins).product()
+}
+
+fn part2(race: &Race) -> u64 {
+    race.wins()
+}
+
+/// Both interpretations of the race sheet
+pub struct Races {
+    /// Each column is a separate race
+    spaced: Vec<Race>,
+    /// The digits on each line form a single number
+    kerned: Race,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Race {
+    time: u64,
+    dist: u64,
+}
+
+impl Race {
+    /// Returns the number of whole millisecond press times which beat the record distance.
+    /// Pressing for t gives t * (time - t), which beats dist for t strictly between the roots of
+    /// t^2 - time t + dist = 0, ie (time +/- sqrt(time^2 - 4 dist)) / 2
+    fn wins(&self) -> u64 {
+        let Some(disc) = (self.time * self.time).checked_sub(4 * self.dist) else {
+            // No real roots, so the record can't be beaten
+            return 0;
+        };
+
+        // First winning press time is the smallest integer strictly above the lower root
+        let first = match exact_sqrt(disc) {
+            // Lower root is (time - root) / 2 which may be an integer or half an integer
+            Some(root) => (self.time - root) / 2 + 1,
+            // Root is irrational and lies strictly between isqrt and isqrt + 1
+            None => (self.time - isqrt(disc) - 1) / 2 + 1,
+        };
+
+        // Winning press times are symmetrical about time / 2
+        (self.time + 1).saturating_sub(2 * first)
+    }
+}
+
+// Input parsing
+
+fn parse_races(input: &str) -> Result<Races, Box<dyn Error>> {
+    let mut lines = input.lines();
+
+    let mut line = |heading: &str| {
+        lines
+            .next()
+            .and_then(|line| line.strip_prefix(heading))
+            .ok_or_else(|| format!("Expected '{heading}' line"))
+    };
+
+    let times = line("Time:")?;
+    let dists = line("Distance:")?;
+
+    let (time_list, dist_list) = (parse_numbers(times)?, parse_numbers(dists)?);
+
+    if time_list.len() != dist_list.len() {
+        Err("Number of times and distances differ")?;
+    }
+
+    let spaced = time_list
+        .into_iter()
+        .zip(dist_list)
+        .map(|(time, dist)| Race { time, dist })
+        .collect::<Vec<_>>();
+
+    let kerned = Race {
+        time: parse_kerned(times)?,
+        dist: parse_kerned(dists)?,
+    };
+
+    for race in spaced.iter().chain([&kerned]) {
+        if race.time.checked_mul(race.time).is_none() || race.dist.checked_mul(4).is_none() {
+            Err(format!("Race {race:?} is too long"))?;
+        }
+    }
+
+    Ok(Races { spaced, kerned })
+}
+
+/// Parses space separated numbers
+fn parse_numbers(line: &str) -> Result<Vec<u64>, Box<dyn Error>> {
+    line.split_whitespace()
+        .map(|n| {
+            n.parse()
+                .map_err(|e| format!("Invalid number '{n}': {e}").into())
+        })
+        .collect()
+}
+
+/// Parses the digits on a line ignoring spaces as a single number
+fn parse_kerned(line: &str) -> Result<u64, Box<dyn Error>> {
+    let digits = line.split_whitespace().collect::<String>();
+
+    Ok(digits
+        .parse()
+        .map_err(|e| format!("Invalid number '{digits}': {e}"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(Day06);
+
+    /// Counts the winning press times by trying each one
+    fn wins_loop(race: &Race) -> u64 {
+        (1..race.time)
+            .filter(|t| t * (race.time - t) > race.dist)
+            .count() as u64
+    }
+
+    #[test]
+    fn test_wins() {
+        for time in 0..=60 {
+            for dist in 0..=time * time / 4 + 1 {
+                let race = Race { time, dist };
+                assert_eq!(race.wins(), wins_loop(&race), "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let races = parse_races("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+
+        assert_eq!(
+            races.spaced,
+            vec![
+                Race { time: 7, dist: 9 },
+                Race { time: 15, dist: 40 },
+                Race {
+                    time: 30,
+                    dist: 200
+                }
+            ]
+        );
+        assert_eq!(
+            races.kerned,
+            Race {
+                time: 71530,
+                dist: 940200
+            }
+        );
+
+        assert!(parse_races("Time: 7 15\nDistance: 9\n").is_err());
+        assert!(parse_races("Time: 7\n").is_err());
+        assert!(parse_races("Time: 7\nDistance: x\n").is_err());
+        assert!(parse_races("Time: 5000000000\nDistance: 1\n").is_err());
+    }
+}
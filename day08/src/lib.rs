@@ -0,0 +1,184 @@
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use aoc::{input::Input, math::lcm_all, solution::Solution};
+
+/// Day 8 solution
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: usize = 8;
+
+    type Input = (Vec<Dir>, HashMap<String, (String, String)>);
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        let lines = input.parse_vec(input_transform)?;
+
+        Ok(parse_input(&lines))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (dirs, loc_map) = input;
+
+        part1(dirs, loc_map)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let (dirs, loc_map) = input;
+
+        part2(dirs, loc_map)
+    }
+}
+
+fn part1(dirs: &[Dir], loc_map: &HashMap<String, (String, String)>) -> u64 {
+    let mut steps = 0;
+
+    // Set start location
+    let mut cur_loc = &String::from("AAA");
+
+    // Walk through direction list
+    for d in dirs.iter().cycle() {
+        // Get map entry
+        let ent = loc_map.get(cur_loc).unwrap();
+
+        // Walk in direction
+        cur_loc = match d {
+            Dir::Left => &ent.0,
+            Dir::Right => &ent.1,
+        };
+
+        steps += 1;
+
+        // Finished?
+        if cur_loc == "ZZZ" {
+            break;
+        }
+    }
+
+    steps
+}
+
+fn part2(dirs: &[Dir], loc_map: &HashMap<String, (String, String)>) -> u64 {
+    // Find starting locations
+    let start_locs = loc_map
+        .keys()
+        .filter(|loc| loc.ends_with('A'))
+        .collect::<Vec<&String>>();
+
+    // Calculate the repeat cycle for each start point
+    let repeat_cycle = start_locs
+        .iter()
+        .map(|s| {
+            let mut cur_loc = &s.to_string();
+            let mut steps = 0;
+
+            for d in dirs.iter().cycle() {
+                let ent = loc_map.get(cur_loc).unwrap();
+
+                cur_loc = match d {
+                    Dir::Left => &ent.0,
+                    Dir::Right => &ent.1,
+                };
+
+                steps += 1;
+
+                if cur_loc.ends_with('Z') {
+                    break;
+                }
+            }
+
+            steps as u64
+        })
+        .collect::<Vec<u64>>();
+
+    // Calculate the LCM of the repeat cycles
+    lcm_all(repeat_cycle).expect("LCM overflows")
+}
+
+pub enum Dir {
+    Left,
+    Right,
+}
+
+// Input parsing
+
+fn input_transform(line: String) -> String {
+    line
+}
+
+fn parse_input(lines: &[String]) -> (Vec<Dir>, HashMap<String, (String, String)>) {
+    let mut line_iter = lines.iter();
+
+    let dirs = line_iter
+        .next()
+        .unwrap()
+        .chars()
+        .map(|c| match c {
+            'L' => Dir::Left,
+            'R' => Dir::Right,
+            _ => panic!("Invalid direction"),
+        })
+        .collect();
+
+    line_iter.next();
+
+    let mut loc_map = HashMap::new();
+
+    for line in line_iter {
+        let mut split1 = line.split('=');
+        let loc = split1.next().unwrap().trim().to_string();
+        let dirs = split1
+            .next()
+            .unwrap()
+            .trim()
+            .trim_start_matches('(')
+            .trim_end_matches(')');
+        let mut split2 = dirs.split(',');
+        let left = split2.next().unwrap().to_string();
+        let right = split2.next().unwrap().trim().to_string();
+        loc_map.insert(loc, (left, right));
+    }
+
+    (dirs, loc_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::parse_test_vec;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE2: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+        let (dirs, loc_map) = parse_input(&input);
+
+        assert_eq!(part1(&dirs, &loc_map), 6);
+    }
+
+    #[test]
+    fn test2() {
+        let input = parse_test_vec(EXAMPLE2, input_transform).unwrap();
+        let (dirs, loc_map) = parse_input(&input);
+
+        assert_eq!(part2(&dirs, &loc_map), 6);
+    }
+}
@@ -0,0 +1,81 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{input::Input, solution::Solution};
+
+/// Day 9 solution
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: usize = 9;
+
+    type Input = Vec<InputEnt>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_vec(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(input: &[InputEnt]) -> i64 {
+    let mut result: i64 = 0;
+
+    for v in input {
+        result += v[v.len() - 1] + calc_diffs_right(v);
+    }
+
+    result
+}
+
+fn calc_diffs_right(v1: &[i64]) -> i64 {
+    let v2 = v1.windows(2).map(|x| x[1] - x[0]).collect::<Vec<i64>>();
+
+    if v2.iter().any(|v| *v != 0) {
+        v2[v2.len() - 1] + calc_diffs_right(&v2)
+    } else {
+        0
+    }
+}
+
+fn part2(input: &[InputEnt]) -> i64 {
+    let mut result: i64 = 0;
+
+    for v in input {
+        result += v[0] - calc_diffs_left(v);
+    }
+
+    result
+}
+
+fn calc_diffs_left(v1: &[i64]) -> i64 {
+    let v2 = v1.windows(2).map(|x| x[1] - x[0]).collect::<Vec<i64>>();
+
+    if v2.iter().any(|v| *v != 0) {
+        v2[0] - calc_diffs_left(&v2)
+    } else {
+        0
+    }
+}
+
+// Input parsing
+
+type InputEnt = Vec<i64>;
+
+fn input_transform(line: String) -> InputEnt {
+    line.split_ascii_whitespace()
+        .map(|t| t.parse::<i64>().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(Day09);
+}
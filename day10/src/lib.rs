@@ -0,0 +1,288 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{
+    geom::{Dir4, Pos, Vec2},
+    grid::Grid,
+    input::{Input, LineError, LinesError},
+    polygon::interior_points,
+    solution::Solution,
+};
+
+/// Day 10 solution
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = (Grid<Pipe>, Pos);
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        let mut map = input.parse_grid(input_transform)?;
+        let start = find_start(&map).map_err(|e| format!("{}: {e}", input.name()))?;
+
+        input.try_parse_str(|text| connect_start(&mut map, start, text))?;
+
+        Ok((map, start))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (map, start) = input;
+
+        part1(map, *start)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let (map, start) = input;
+
+        part2(map, *start)
+    }
+}
+
+fn find_start(map: &Grid<Pipe>) -> Result<Pos, &'static str> {
+    map.find(|c| *c == Pipe::Start)
+        .map(Pos::from)
+        .ok_or("No start position 'S' found")
+}
+
+/// Replaces the start with the pipe which connects to its neighbours. Errors refer to the
+/// lines of the text the map was parsed from
+fn connect_start<'a>(
+    map: &mut Grid<Pipe>,
+    start: Pos,
+    text: &'a str,
+) -> Result<(), LinesError<'a>> {
+    // Which directions connect to the start?
+    let dirs = Dir4::ALL
+        .into_iter()
+        .filter(|&dir| {
+            map.step(start, dir)
+                .is_some_and(|pos| map[pos].connects(dir.reverse()))
+        })
+        .collect::<Vec<_>>();
+
+    let start_pipe = match dirs[..] {
+        [Dir4::N, Dir4::E] => Pipe::NE,
+        [Dir4::N, Dir4::S] => Pipe::NS,
+        [Dir4::N, Dir4::W] => Pipe::NW,
+        [Dir4::E, Dir4::S] => Pipe::SE,
+        [Dir4::E, Dir4::W] => Pipe::EW,
+        [Dir4::S, Dir4::W] => Pipe::SW,
+        _ => {
+            let line = text.lines().nth(start.y).unwrap_or_default();
+            let error = LineError::new(format!(
+                "Start connects to {} pipes, expected 2",
+                dirs.len()
+            ))
+            .at(start.x..start.x + 1);
+
+            let mut errors = LinesError::new();
+            errors.push(line, error);
+
+            return Err(errors);
+        }
+    };
+
+    map[start] = start_pipe;
+
+    Ok(())
+}
+
+fn start_dir(map: &Grid<Pipe>, pos: Pos) -> Dir4 {
+    // Choose a start direction
+    match map[pos] {
+        Pipe::NS | Pipe::NE | Pipe::NW => Dir4::S,
+        Pipe::EW | Pipe::SE => Dir4::W,
+        Pipe::SW => Dir4::E,
+        _ => panic!("Invalid start pipe"),
+    }
+}
+
+fn part1(map: &Grid<Pipe>, start: Pos) -> u64 {
+    let mut pos = start;
+    let mut dir_from = start_dir(map, pos);
+
+    // Walk the loop
+    let mut steps = 0;
+
+    loop {
+        dir_from = map[pos].next_dir(dir_from);
+        pos = map.step(pos, dir_from).expect("Loop leaves the map");
+
+        steps += 1;
+
+        if pos == start {
+            break;
+        }
+    }
+
+    steps / 2
+}
+
+fn part2(map: &Grid<Pipe>, start: Pos) -> u64 {
+    let mut pos = start;
+    let mut dir_from = start_dir(map, pos);
+
+    // Walk the loop, recording each square as a polygon vertex
+    let mut vertices = Vec::new();
+
+    loop {
+        vertices.push(Vec2::new(pos.x as i64, pos.y as i64));
+
+        dir_from = map[pos].next_dir(dir_from);
+        pos = map.step(pos, dir_from).expect("Loop leaves the map");
+
+        if pos == start {
+            break;
+        }
+    }
+
+    // Squares strictly inside the loop
+    interior_points(&vertices) as u64
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Pipe {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+impl Pipe {
+    /// Returns true if the pipe has an opening in a direction
+    fn connects(&self, dir: Dir4) -> bool {
+        matches!(
+            (self, dir),
+            (Pipe::NS | Pipe::NE | Pipe::NW, Dir4::N)
+                | (Pipe::NS | Pipe::SE | Pipe::SW, Dir4::S)
+                | (Pipe::EW | Pipe::NE | Pipe::SE, Dir4::E)
+                | (Pipe::EW | Pipe::NW | Pipe::SW, Dir4::W)
+        )
+    }
+
+    fn next_dir(&self, dir: Dir4) -> Dir4 {
+        match self {
+            Pipe::NS => match dir {
+                Dir4::S => Dir4::S,
+                Dir4::N => Dir4::N,
+                _ => panic!("Invalid direction {dir:?} for {self:?}"),
+            },
+            Pipe::EW => match dir {
+                Dir4::E => Dir4::E,
+                Dir4::W => Dir4::W,
+                _ => panic!("Invalid direction {dir:?} for {self:?}"),
+            },
+            Pipe::NE => match dir {
+                Dir4::S => Dir4::E,
+                Dir4::W => Dir4::N,
+                _ => panic!("Invalid direction {dir:?} for {self:?}"),
+            },
+            Pipe::NW => match dir {
+                Dir4::S => Dir4::W,
+                Dir4::E => Dir4::N,
+                _ => panic!("Invalid direction {dir:?} for {self:?}"),
+            },
+            Pipe::SW => match dir {
+                Dir4::E => Dir4::S,
+                Dir4::N => Dir4::W,
+                _ => panic!("Invalid direction {dir:?} for {self:?}"),
+            },
+            Pipe::SE => match dir {
+                Dir4::N => Dir4::E,
+                Dir4::W => Dir4::S,
+                _ => panic!("Invalid direction {dir:?} for {self:?}"),
+            },
+            _ => panic!("Invalid pipe"),
+        }
+    }
+}
+
+// Input parsing
+
+fn input_transform(c: char) -> Pipe {
+    match c {
+        '|' => Pipe::NS,
+        '-' => Pipe::EW,
+        'L' => Pipe::NE,
+        'J' => Pipe::NW,
+        '7' => Pipe::SW,
+        'F' => Pipe::SE,
+        '.' => Pipe::Ground,
+        'S' => Pipe::Start,
+        _ => panic!("Invalid char"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::{grid::parse_test_grid, input::try_parse_test_str};
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....";
+
+    const EXAMPLE2: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+    fn parse_test(text: &str) -> Result<(Grid<Pipe>, Pos), Box<dyn Error>> {
+        let mut map = parse_test_grid(text, input_transform)?;
+        let start = find_start(&map)?;
+
+        try_parse_test_str(text, |text| connect_start(&mut map, start, text))?;
+
+        Ok((map, start))
+    }
+
+    #[test]
+    fn test1() {
+        let (input, start) = parse_test(EXAMPLE1).unwrap();
+
+        assert_eq!(part1(&input, start), 4);
+    }
+
+    #[test]
+    fn test2() {
+        let (input, start) = parse_test(EXAMPLE2).unwrap();
+
+        assert_eq!(part1(&input, start), 4);
+    }
+
+    #[test]
+    fn test3() {
+        let (input, start) = parse_test(EXAMPLE2).unwrap();
+
+        assert_eq!(part2(&input, start), 1);
+    }
+
+    #[test]
+    fn test_bad_start() {
+        assert_eq!(
+            parse_test(".....\n.-7..\n.....").err().unwrap().to_string(),
+            "No start position 'S' found"
+        );
+
+        assert_eq!(
+            parse_test(".....\n.S-..\n.....").err().unwrap().to_string(),
+            "\
+error: Start connects to 1 pipes, expected 2
+ --> <test>:2:2
+  |
+2 | .S-..
+  |  ^
+"
+        );
+    }
+}
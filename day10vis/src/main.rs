@@ -1,12 +1,12 @@
 use std::{
-    cmp::max,
     collections::{HashSet, VecDeque},
     error::Error,
 };
 
-use colorgrad::CustomGradient;
-
-use aoc::{gif::Gif, input::parse_input_vec};
+use aoc::{
+    gif::{Canvas, Gif, Palette, Ramp},
+    input::parse_input_vec,
+};
 
 fn main() -> Result<(), Box<dyn Error>> {
     // Get input
@@ -75,22 +75,24 @@ const DEPTH_DRAW: usize = 30;
 
 fn visualise(map: &[MapRow], start_x: usize, start_y: usize) -> Result<(), Box<dyn Error>> {
     // Build palette
-    let mut palette: Vec<[u8; 3]> = vec![[0, 0, 0], [255, 255, 255], [128, 128, 128], [0, 0, 255]];
-    let range_start = palette.len();
-
-    let grad = CustomGradient::new()
-        .html_colors(&["seagreen", "gold", "deeppink"])
-        .build()?;
-
-    for c in 0..=RANGE_COLS {
-        let col = grad.at(c as f64 / RANGE_COLS as f64).to_rgba8();
-        palette.push([col[0], col[1], col[2]]);
-    }
+    let mut palette = Palette::new();
+
+    palette.colour("background", [0, 0, 0])?;
+    let pipe_colour = palette.colour("pipe", [255, 255, 255])?;
+    let outside_colour = palette.colour("outside", [128, 128, 128])?;
+    let loop_colour = palette.colour("loop", [0, 0, 255])?;
+
+    // Sea green -> gold -> deep pink
+    let path_ramp = palette.gradient(
+        "path",
+        &[[46, 139, 87], [255, 215, 0], [255, 20, 147]],
+        RANGE_COLS + 1,
+    )?;
 
     // Create GIF
     let mut gif = Gif::new(
         "vis/day10.gif",
-        palette.as_slice(),
+        palette.colours(),
         (map[0].len() * CELLDIM) as u16,
         (map.len() * CELLDIM) as u16,
         2,
@@ -98,16 +100,16 @@ fn visualise(map: &[MapRow], start_x: usize, start_y: usize) -> Result<(), Box<d
     )?;
 
     // Draw base frame
-    let mut base_frame = gif.empty_frame();
+    let mut base_frame = gif.empty_frame().with_cell_size(CELLDIM, CELLDIM);
 
     for (y, row) in map.iter().enumerate() {
         for (x, p) in row.iter().enumerate() {
-            drawpipe(&mut base_frame, p, x, y, 1);
+            drawpipe(&mut base_frame, p, x, y, pipe_colour);
         }
     }
 
     // Output base frame
-    gif.draw_frame(base_frame.clone(), 0)?;
+    gif.draw_frame(&base_frame, 0)?;
 
     // Start position queue
     let mut queue = VecDeque::new();
@@ -125,6 +127,9 @@ fn visualise(map: &[MapRow], start_x: usize, start_y: usize) -> Result<(), Box<d
         queue.push_back((start_x, start_y, Dir::E, 1));
     }
 
+    // Scratch frame for drawing the path on the base frame
+    let mut path_frame = base_frame.clone();
+
     // Walk state
     let mut last_depth = 0;
     let mut visited = Vec::new();
@@ -138,7 +143,14 @@ fn visualise(map: &[MapRow], start_x: usize, start_y: usize) -> Result<(), Box<d
     while let Some((mut x, mut y, mut dir, depth)) = queue.pop_front() {
         if depth > last_depth + DEPTH_DRAW {
             // Draw a frame
-            draw_path(map, &mut gif, base_frame.clone(), range_start, &visited)?;
+            draw_path(
+                map,
+                &mut gif,
+                &mut path_frame,
+                &base_frame,
+                path_ramp,
+                &visited,
+            )?;
 
             last_depth = depth;
         }
@@ -207,22 +219,29 @@ fn visualise(map: &[MapRow], start_x: usize, start_y: usize) -> Result<(), Box<d
                     _ => panic!("Invalid pipe"),
                 }
 
-                drawpipe(&mut frame, &map[y][x], x, y, 3);
+                drawpipe(&mut frame, &map[y][x], x, y, loop_colour);
             } else if pipe_count & 1 == 0 {
                 // Even number of pipes crossed means this unvisited square is outide the loop
                 // Draw in gray
-                drawpipe(&mut frame, &map[y][x], x, y, 2);
+                drawpipe(&mut frame, &map[y][x], x, y, outside_colour);
             }
         }
     });
 
     // Draw final path frame
-    draw_path(map, &mut gif, base_frame.clone(), range_start, &visited)?;
+    draw_path(
+        map,
+        &mut gif,
+        &mut path_frame,
+        &base_frame,
+        path_ramp,
+        &visited,
+    )?;
 
     gif.delay(1000)?;
 
     // Draw contained frame
-    gif.draw_frame(frame, 1)?;
+    gif.draw_frame(&frame, 1)?;
 
     gif.delay(1000)?;
 
@@ -233,20 +252,22 @@ fn visualise(map: &[MapRow], start_x: usize, start_y: usize) -> Result<(), Box<d
 fn draw_path(
     map: &[MapRow],
     gif: &mut Gif,
-    mut frame: Vec<Vec<u8>>,
-    range_start: usize,
+    frame: &mut Canvas,
+    base_frame: &Canvas,
+    ramp: Ramp,
     visited: &[(usize, usize)],
 ) -> Result<(), Box<dyn Error>> {
+    // Reset the frame to the base frame, reusing its pixels
+    frame.clone_from(base_frame);
+
     // Draw frame
     for (i, (px, py)) in visited.iter().enumerate() {
-        let col = (i * RANGE_COLS) / max(1, visited.len() - 1);
-
         drawpipe(
-            &mut frame,
+            frame,
             &map[*py][*px],
             *px,
             *py,
-            (range_start + col) as u8,
+            ramp.at(i, visited.len() - 1),
         );
     }
 
@@ -256,7 +277,7 @@ fn draw_path(
 }
 
 // Function to draw pipe segment
-fn drawpipe(frame: &mut [Vec<u8>], p: &Pipe, x: usize, y: usize, colour: u8) {
+fn drawpipe(frame: &mut Canvas, p: &Pipe, x: usize, y: usize, colour: u8) {
     // Get bitmap for pipe
     let bm = match p {
         Pipe::NS => [[0, 1, 0], [0, 1, 0], [0, 1, 0]],
@@ -269,16 +290,9 @@ fn drawpipe(frame: &mut [Vec<u8>], p: &Pipe, x: usize, y: usize, colour: u8) {
         _ => [[0, 0, 0], [0, 0, 0], [0, 0, 0]],
     };
 
-    // Work out frame position
-    let fy = y * CELLDIM;
-    let fx = x * CELLDIM;
-
     // Draw the pixels
-    for (yadd, r) in bm.iter().enumerate() {
-        for (xadd, c) in r.iter().enumerate() {
-            frame[fy + yadd][fx + xadd] = if *c == 1 { colour } else { 0 };
-        }
-    }
+    frame.fill_cell(x, y, 0);
+    frame.blit_mask_cell(x, y, &bm, colour);
 }
 
 #[derive(Debug, PartialEq)]
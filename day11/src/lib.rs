@@ -0,0 +1,105 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{grid::Grid, input::Input, solution::Solution};
+
+/// Day 11 solution
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = Grid<bool>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_grid(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        dists_with_expansion(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        dists_with_expansion(input, 1_000_000)
+    }
+}
+
+fn dists_with_expansion(map: &Grid<bool>, expansion: u64) -> u64 {
+    // Find empty rows and columns
+    let x_found = map
+        .columns()
+        .map(|mut c| c.any(|g| *g))
+        .collect::<Vec<bool>>();
+
+    let y_found = map
+        .rows()
+        .map(|r| r.iter().any(|g| *g))
+        .collect::<Vec<bool>>();
+
+    // Create x and y mapping
+    let mut xmap = Vec::new();
+    let mut ymap = Vec::new();
+
+    let mut mapped = 0;
+    for found in x_found.into_iter() {
+        mapped += if found { 1u64 } else { expansion };
+        xmap.push(mapped);
+    }
+
+    let mut mapped = 0;
+    for found in y_found.into_iter() {
+        mapped += if found { 1u64 } else { expansion };
+        ymap.push(mapped);
+    }
+
+    // Get galaxy positions via mappings
+    let positions = map
+        .enumerate()
+        .filter(|(_, g)| **g)
+        .map(|((x, y), _)| (xmap[x], ymap[y]))
+        .collect::<Vec<_>>();
+
+    // Find distances
+    let mut dist_sum = 0;
+
+    for (i, (x1, y1)) in positions.iter().enumerate() {
+        for (x2, y2) in positions[i + 1..].iter() {
+            let dist = i64::abs(*x1 as i64 - *x2 as i64) + i64::abs(*y1 as i64 - *y2 as i64);
+            dist_sum += dist as u64;
+        }
+    }
+
+    dist_sum
+}
+
+// Input parsing
+
+fn input_transform(c: char) -> bool {
+    c == '#'
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::grid::parse_test_grid;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(dists_with_expansion(&input, 2), 374);
+        assert_eq!(dists_with_expansion(&input, 10), 1030);
+        assert_eq!(dists_with_expansion(&input, 100), 8410);
+    }
+}
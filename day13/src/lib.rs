@@ -0,0 +1,148 @@
+use std::{cmp::min, error::Error, fmt::Display};
+
+use aoc::{grid::Grid, input::Input, solution::Solution};
+
+/// Day 13 solution
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    type Input = Vec<Board>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_sections(build_board)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(input: &[Board]) -> u64 {
+    sum_reflections(input, 0)
+}
+
+fn part2(input: &[Board]) -> u64 {
+    sum_reflections(input, 1)
+}
+
+#[derive(Debug)]
+enum Reflection {
+    Horiz(usize),
+    Vert(usize),
+}
+
+fn sum_reflections(input: &[Board], dist: u64) -> u64 {
+    input.iter().fold(0, |acc, board| {
+        acc + match find_reflection(board, dist) {
+            Reflection::Horiz(y) => y as u64 * 100,
+            Reflection::Vert(x) => x as u64,
+        }
+    })
+}
+
+fn find_reflection(board: &Board, dist: u64) -> Reflection {
+    // Horizontal
+    for y in 1..board.height() {
+        let reflection = Reflection::Horiz(y);
+
+        if test_reflection(board, &reflection) == dist {
+            return reflection;
+        }
+    }
+
+    // Vertical
+    for x in 1..board.width() {
+        let reflection = Reflection::Vert(x);
+
+        if test_reflection(board, &reflection) == dist {
+            return reflection;
+        }
+    }
+
+    panic!("No reflection")
+}
+
+fn cols_dist(board: &Board, c1: usize, c2: usize) -> u64 {
+    board
+        .column(c1)
+        .zip(board.column(c2))
+        .fold(0, |acc, (&p1, &p2)| if p1 != p2 { acc + 1 } else { acc })
+}
+
+fn rows_dist(board: &Board, r1: usize, r2: usize) -> u64 {
+    board
+        .row(r1)
+        .iter()
+        .zip(board.row(r2))
+        .fold(0, |acc, (&p1, &p2)| if p1 != p2 { acc + 1 } else { acc })
+}
+
+fn test_reflection(board: &Board, reflection: &Reflection) -> u64 {
+    match reflection {
+        Reflection::Horiz(y) => (0..=min(y - 1, board.height() - (y + 1)))
+            .map(|i| rows_dist(board, y - (i + 1), y + i))
+            .sum(),
+        Reflection::Vert(x) => (0..=min(x - 1, board.width() - (x + 1)))
+            .map(|i| cols_dist(board, x - (i + 1), x + i))
+            .sum(),
+    }
+}
+
+// Input parsing
+
+type Board = Grid<bool>;
+
+fn build_board(lines: &[&str]) -> Board {
+    let rows = lines
+        .iter()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' => true,
+                    '.' => false,
+                    _ => panic!("Invalid char"),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    Grid::from_rows(rows).expect("Board is not rectangular")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::parse_test_sections;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_sections(EXAMPLE1, build_board);
+
+        assert_eq!(part1(&input), 405);
+        assert_eq!(part2(&input), 400);
+    }
+}
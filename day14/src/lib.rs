@@ -0,0 +1,233 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{cycle::History, geom::Dir4, grid::Grid, input::Input, solution::Solution};
+
+/// Day 14 solution
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: usize = 14;
+
+    type Input = Grid<State>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_grid(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(map: &Grid<State>) -> u64 {
+    let mut map = map.clone();
+
+    // Roll rocks north
+    roll(&mut map, Dir4::N);
+
+    // Calculate load
+    calc_load(&map)
+}
+
+fn part2(map: &Grid<State>) -> u64 {
+    // Find the spin cycle loop
+    let history = History::find(map.clone(), spin);
+
+    // Calculate the load at 1,000,000,000 spins
+    calc_load(history.state_at(1_000_000_000))
+}
+
+fn spin(map: &Grid<State>) -> Grid<State> {
+    let mut map = map.clone();
+
+    // Roll the rocks
+    roll(&mut map, Dir4::N);
+    roll(&mut map, Dir4::W);
+    roll(&mut map, Dir4::S);
+    roll(&mut map, Dir4::E);
+
+    map
+}
+
+fn roll(map: &mut Grid<State>, dir: Dir4) {
+    match dir {
+        Dir4::N => (0..map.width()).for_each(|x| {
+            (1..map.height()).for_each(|y| {
+                if map[(x, y)] == State::Rock {
+                    let lx = x;
+                    roll_rock(map, x, y, (0..y).rev().map(move |ly| (lx, ly)));
+                }
+            });
+        }),
+        Dir4::S => (0..map.width()).for_each(|x| {
+            (0..(map.height() - 1)).rev().for_each(|y| {
+                if map[(x, y)] == State::Rock {
+                    let lx = x;
+                    roll_rock(map, x, y, ((y + 1)..map.height()).map(move |ly| (lx, ly)));
+                }
+            });
+        }),
+        Dir4::E => (0..map.height()).for_each(|y| {
+            (0..(map.width() - 1)).rev().for_each(|x| {
+                if map[(x, y)] == State::Rock {
+                    let ly = y;
+                    roll_rock(map, x, y, ((x + 1)..map.width()).map(move |lx| (lx, ly)));
+                }
+            });
+        }),
+        Dir4::W => (0..map.height()).for_each(|y| {
+            (1..map.width()).for_each(|x| {
+                if map[(x, y)] == State::Rock {
+                    let ly = y;
+                    roll_rock(map, x, y, (0..x).rev().map(move |lx| (lx, ly)));
+                }
+            });
+        }),
+    }
+}
+
+fn roll_rock(
+    map: &mut Grid<State>,
+    x: usize,
+    y: usize,
+    pos_iter: impl Iterator<Item = (usize, usize)>,
+) {
+    map[(x, y)] = State::Empty;
+
+    let (mut rx, mut ry) = (x, y);
+
+    for (cx, cy) in pos_iter {
+        if map[(cx, cy)] == State::Empty {
+            (rx, ry) = (cx, cy)
+        } else {
+            break;
+        }
+    }
+
+    map[(rx, ry)] = State::Rock;
+}
+
+fn calc_load(map: &Grid<State>) -> u64 {
+    // Calculate load
+    map.rows()
+        .rev()
+        .enumerate()
+        .map(|(mult, row)| {
+            row.iter().filter(|p| **p == State::Rock).count() as u64 * (mult as u64 + 1)
+        })
+        .sum()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum State {
+    Empty,
+    Rock,
+    Cube,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            State::Empty => '.',
+            State::Rock => 'O',
+            State::Cube => '#',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+// Input parsing
+
+fn input_transform(c: char) -> State {
+    match c {
+        '.' => State::Empty,
+        '#' => State::Cube,
+        'O' => State::Rock,
+        _ => panic!("Invalid char"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::grid::parse_test_grid;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(part1(&input), 136);
+        assert_eq!(part2(&input), 64);
+    }
+
+    #[test]
+    fn test2() {
+        let mut map = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+
+        for i in 0..3 {
+            map = spin(&map);
+
+            let expected = match i {
+                0 => {
+                    "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."
+                }
+                1 => {
+                    "\
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O"
+                }
+                2 => {
+                    "\
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O"
+                }
+                _ => unreachable!(),
+            };
+
+            assert_eq!(map.to_string(), expected, "Map incorrect")
+        }
+    }
+}
@@ -5,7 +5,7 @@ use std::{
 };
 
 use aoc::{
-    gif::Gif,
+    gif::{Canvas, Gif},
     input::{parse_input_vec, parse_test_vec},
 };
 
@@ -168,6 +168,7 @@ fn roll(gif: &mut Gif, map: &mut [InputEnt], dir: Dir, rate: &Rate) -> Result<()
         // Blank out rocks
         for &((cx, cy), _, _) in moves.iter() {
             draw_cell(&mut frame, cx, cy, &State::Empty);
+            gif.mark_dirty(cx, cy, SQUARE, SQUARE);
         }
 
         // Move rocks
@@ -179,9 +180,10 @@ fn roll(gif: &mut Gif, map: &mut [InputEnt], dir: Dir, rate: &Rate) -> Result<()
         // Draw rocks
         for &((cx, cy), _, _) in moves.iter() {
             draw_cell(&mut frame, cx, cy, &State::Rock);
+            gif.mark_dirty(cx, cy, SQUARE, SQUARE);
         }
 
-        gif.draw_frame(frame.clone(), 1)?;
+        gif.draw_frame(&frame, 1)?;
 
         // Filter moves
         moves = moves
@@ -240,12 +242,12 @@ fn roll_rock(
 fn draw_map(gif: &mut Gif, map: &[InputEnt]) -> Result<(), Box<dyn Error>> {
     let frame = draw_frame(gif, map);
 
-    gif.draw_frame(frame, 5)?;
+    gif.draw_frame(&frame, 5)?;
 
     Ok(())
 }
 
-fn draw_frame(gif: &Gif, map: &[InputEnt]) -> Vec<Vec<u8>> {
+fn draw_frame(gif: &Gif, map: &[InputEnt]) -> Canvas {
     let mut frame = gif.empty_frame();
 
     let mut gy = 0;
@@ -265,70 +267,23 @@ fn draw_frame(gif: &Gif, map: &[InputEnt]) -> Vec<Vec<u8>> {
     frame
 }
 
-fn draw_cell(frame: &mut Vec<Vec<u8>>, gx: usize, gy: usize, cell: &State) {
-    let draw_pixels = |frame: &mut Vec<Vec<u8>>,
-                       gx: usize,
-                       gy: usize,
-                       bmp: &[[_; SQUARE]; SQUARE],
-                       colour: u8| {
-        for y in 0..SQUARE {
-            for x in 0..SQUARE {
-                if bmp[y][x] == 1 {
-                    frame[gy + y][gx + x] = colour;
-                }
-            }
-        }
-    };
-
+fn draw_cell(frame: &mut Canvas, gx: usize, gy: usize, cell: &State) {
     match cell {
-        State::Empty => {
-            draw_pixels(
-                frame,
-                gx,
-                gy,
-                &[
-                    [1, 1, 1, 1, 1, 1],
-                    [1, 1, 1, 1, 1, 1],
-                    [1, 1, 1, 1, 1, 1],
-                    [1, 1, 1, 1, 1, 1],
-                    [1, 1, 1, 1, 1, 1],
-                    [1, 1, 1, 1, 1, 1],
-                ],
-                0,
-            );
-        }
-        State::Rock => {
-            draw_pixels(
-                frame,
-                gx,
-                gy,
-                &[
-                    [0, 0, 0, 0, 0, 0],
-                    [0, 0, 1, 1, 1, 0],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 0, 1, 1, 1, 0],
-                ],
-                1,
-            );
-        }
-        State::Cube => {
-            draw_pixels(
-                frame,
-                gx,
-                gy,
-                &[
-                    [0, 0, 0, 0, 0, 0],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 1, 1, 1, 1, 1],
-                    [0, 1, 1, 1, 1, 1],
-                ],
-                2,
-            );
-        }
+        State::Empty => frame.fill_rect(gx, gy, SQUARE, SQUARE, 0),
+        State::Rock => frame.blit_mask(
+            gx,
+            gy,
+            &[
+                [0, 0, 0, 0, 0, 0],
+                [0, 0, 1, 1, 1, 0],
+                [0, 1, 1, 1, 1, 1],
+                [0, 1, 1, 1, 1, 1],
+                [0, 1, 1, 1, 1, 1],
+                [0, 0, 1, 1, 1, 0],
+            ],
+            1,
+        ),
+        State::Cube => frame.fill_rect(gx + 1, gy + 1, SQUARE - 1, SQUARE - 1, 2),
     }
 }
 
@@ -0,0 +1,217 @@
+use std::{
+    cmp::max,
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt::Display,
+};
+
+use aoc::{
+    geom::{Dir4, Pos},
+    grid::Grid,
+    input::Input,
+    solution::Solution,
+};
+
+/// Day 16 solution
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: usize = 16;
+
+    type Input = Grid<State>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_grid(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(map: &Grid<State>) -> u64 {
+    energise(map, Pos::new(0, 0), Dir4::E)
+}
+
+fn part2(map: &Grid<State>) -> u64 {
+    let mut result = 0;
+
+    // Top and bottom rows
+    for x in 0..map.width() {
+        result = max(result, energise(map, Pos::new(x, 0), Dir4::S));
+        result = max(
+            result,
+            energise(map, Pos::new(x, map.height() - 1), Dir4::N),
+        );
+    }
+
+    // Left and Right columns
+    for y in 0..map.height() {
+        result = max(result, energise(map, Pos::new(0, y), Dir4::E));
+        result = max(result, energise(map, Pos::new(map.width() - 1, y), Dir4::W));
+    }
+
+    result
+}
+
+fn energise(map: &Grid<State>, pos: Pos, dir: Dir4) -> u64 {
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    // Add initial position and direction
+    queue.push_back((pos, dir));
+
+    // Get next queue entry
+    while let Some((pos, dir)) = queue.pop_front() {
+        // Build hash set entry
+        let visited_ent = (pos, dir);
+
+        // Already been here travelling in this direction?
+        if visited.contains(&visited_ent) {
+            continue;
+        }
+
+        // Add to visited
+        visited.insert(visited_ent);
+
+        match map[pos] {
+            State::Empty => {
+                // Continue on this path
+                if let Some(pos) = map.step(pos, dir) {
+                    queue.push_back((pos, dir));
+                }
+            }
+            State::MirrorNESW => {
+                // Work out new direction
+                let new_dir = match dir {
+                    Dir4::N => Dir4::E,
+                    Dir4::E => Dir4::N,
+                    Dir4::S => Dir4::W,
+                    Dir4::W => Dir4::S,
+                };
+
+                // Move in new direction
+                if let Some(pos) = map.step(pos, new_dir) {
+                    queue.push_back((pos, new_dir));
+                }
+            }
+            State::MirrorNWSE => {
+                // Work out new direction
+                let new_dir = match dir {
+                    Dir4::N => Dir4::W,
+                    Dir4::E => Dir4::S,
+                    Dir4::S => Dir4::E,
+                    Dir4::W => Dir4::N,
+                };
+
+                // Move in new direction
+                if let Some(pos) = map.step(pos, new_dir) {
+                    queue.push_back((pos, new_dir));
+                }
+            }
+            State::SplitterHoriz => match dir {
+                Dir4::E | Dir4::W => {
+                    // Continue on this path
+                    if let Some(pos) = map.step(pos, dir) {
+                        queue.push_back((pos, dir));
+                    }
+                }
+                Dir4::S | Dir4::N => {
+                    // Split east
+                    let dir1 = Dir4::E;
+
+                    if let Some(pos) = map.step(pos, dir1) {
+                        queue.push_back((pos, dir1));
+                    }
+
+                    // Split west
+                    let dir2 = Dir4::W;
+
+                    if let Some(pos) = map.step(pos, dir2) {
+                        queue.push_back((pos, dir2));
+                    }
+                }
+            },
+            State::SplitterVert => match dir {
+                Dir4::S | Dir4::N => {
+                    // Continue on this path
+                    if let Some(pos) = map.step(pos, dir) {
+                        queue.push_back((pos, dir));
+                    }
+                }
+                Dir4::E | Dir4::W => {
+                    // Split north
+                    let dir1 = Dir4::N;
+
+                    if let Some(pos) = map.step(pos, dir1) {
+                        queue.push_back((pos, dir1));
+                    }
+
+                    // Split south
+                    let dir2 = Dir4::S;
+
+                    if let Some(pos) = map.step(pos, dir2) {
+                        queue.push_back((pos, dir2));
+                    }
+                }
+            },
+        };
+    }
+
+    // Build set of visited locations
+    let visited_set = visited.iter().map(|(pos, _)| pos).collect::<HashSet<_>>();
+
+    visited_set.len() as u64
+}
+
+#[derive(Debug)]
+pub enum State {
+    Empty,
+    MirrorNESW,
+    MirrorNWSE,
+    SplitterHoriz,
+    SplitterVert,
+}
+
+// Input parsing
+
+fn input_transform(c: char) -> State {
+    match c {
+        '.' => State::Empty,
+        '/' => State::MirrorNESW,
+        '\\' => State::MirrorNWSE,
+        '-' => State::SplitterHoriz,
+        '|' => State::SplitterVert,
+        _ => panic!("Unvalid character"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::grid::parse_test_grid;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(part1(&input), 46);
+        assert_eq!(part2(&input), 51);
+    }
+}
@@ -4,7 +4,7 @@ use std::{
 };
 
 use aoc::{
-    gif::Gif,
+    gif::{Format, RgbCanvas},
     input::{parse_input_vec, parse_test_vec},
 };
 
@@ -21,18 +21,20 @@ const EXAMPLE1: &str = "\
 ..//.|....";
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let format = Format::from_args()?;
+
     let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
 
-    render(&input, "vis/day16ex.gif")?;
+    render(&input, "vis/day16ex", format)?;
 
     let input = parse_input_vec(16, input_transform)?;
 
-    render(&input, "vis/day16.gif")?;
+    render(&input, "vis/day16", format)?;
 
     Ok(())
 }
 
-fn render(map: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
+fn render(map: &[InputEnt], file: &str, format: Format) -> Result<(), Box<dyn Error>> {
     let mut max_result = 0;
     let mut best = None;
 
@@ -59,7 +61,7 @@ fn render(map: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
 
     // Render the best path
     if let Some((x, y, dir)) = best {
-        render_path(file, map, x, y, dir)?;
+        render_path(file, format, map, x, y, dir)?;
     }
 
     Ok(())
@@ -68,10 +70,15 @@ fn render(map: &[InputEnt], file: &str) -> Result<(), Box<dyn Error>> {
 const CELLSIZE: usize = 5;
 const MAX_INTENSITY: u8 = 8;
 
+const BLACK: [u8; 3] = [0, 0, 0];
+const MIRROR: [u8; 3] = [128, 128, 255];
+const SPLITTER: [u8; 3] = [255, 128, 128];
+
 type CellState = [u8; 4];
 
 fn render_path(
     file: &str,
+    format: Format,
     map: &[InputEnt],
     x: usize,
     y: usize,
@@ -79,24 +86,23 @@ fn render_path(
 ) -> Result<(), Box<dyn Error>> {
     // Create palette
     let mut palette = vec![
-        [0, 0, 0],       // Black
-        [128, 128, 255], // Mirror
-        [255, 128, 128], // Splitter
-        [0, 0, 0],       // Filler
-        [0, 0, 0],       // Filler
-        [0, 0, 0],       // Filler
-        [0, 0, 0],       // Filler
-        [0, 0, 0],       // Filler
+        BLACK,
+        MIRROR,
+        SPLITTER,
+        [0, 0, 0], // Filler
+        [0, 0, 0], // Filler
+        [0, 0, 0], // Filler
+        [0, 0, 0], // Filler
+        [0, 0, 0], // Filler
     ];
 
     // Shades of white
-    for i in 0u32..(MAX_INTENSITY as u32) {
-        let component = (127 + ((i * 128) / MAX_INTENSITY as u32)) as u8;
-        palette.push([component, component, component]);
+    for i in 1..=MAX_INTENSITY {
+        palette.push(grey(i));
     }
 
-    // Create GIF
-    let mut gif = Gif::new(
+    // Create output
+    let mut sink = format.create(
         file,
         &palette,
         (map[0].len() * CELLSIZE) as u16,
@@ -105,16 +111,19 @@ fn render_path(
         1,
     )?;
 
+    // Blend beams if not limited to the palette
+    let blend = sink.true_colour();
+
     // Create cell states
     let mut cell_state: Vec<Vec<CellState>> =
         vec![vec![CellState::default(); map[0].len()]; map.len()];
 
-    // Create gif frame
-    let mut frame = gif.empty_frame();
+    // Create frame
+    let mut frame = sink.empty_rgb_frame().with_cell_size(CELLSIZE, CELLSIZE);
 
     for y in 0..(map.len()) {
         for x in 0..(map[0].len()) {
-            draw_cell(&mut frame, map, &cell_state, x, y);
+            draw_cell(&mut frame, map, &cell_state, x, y, blend);
         }
     }
 
@@ -130,19 +139,31 @@ fn render_path(
     // Get next queue entry
     while let Some(((x, y), dir, depth)) = queue.pop_front() {
         if depth > last_depth {
-            gif.draw_frame(frame.clone(), 1)?;
+            sink.draw_rgb_frame(&frame, 1)?;
             last_depth = depth;
         }
 
+        // Only this cell is redrawn
+        let (gx, gy) = frame.cell(x, y);
+        sink.mark_dirty(gx, gy, CELLSIZE, CELLSIZE);
+
         // Add intensity
-        if !add_intensity(&mut frame, map, &mut cell_state, x, y, dir.opposite()) {
+        if !add_intensity(
+            &mut frame,
+            map,
+            &mut cell_state,
+            blend,
+            x,
+            y,
+            dir.opposite(),
+        ) {
             continue;
         }
 
         match map[y][x] {
             State::Empty => {
                 // Continue on this path
-                add_intensity(&mut frame, map, &mut cell_state, x, y, dir);
+                add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir);
                 if let Some((nx, ny)) = add_dir(map, x, y, &dir) {
                     queue.push_back(((nx, ny), dir, depth + 1));
                 }
@@ -157,7 +178,7 @@ fn render_path(
                 };
 
                 // Move in new direction
-                add_intensity(&mut frame, map, &mut cell_state, x, y, new_dir);
+                add_intensity(&mut frame, map, &mut cell_state, blend, x, y, new_dir);
                 if let Some((nx, ny)) = add_dir(map, x, y, &new_dir) {
                     queue.push_back(((nx, ny), new_dir, depth + 1));
                 }
@@ -172,7 +193,7 @@ fn render_path(
                 };
 
                 // Move in new direction
-                add_intensity(&mut frame, map, &mut cell_state, x, y, new_dir);
+                add_intensity(&mut frame, map, &mut cell_state, blend, x, y, new_dir);
                 if let Some((nx, ny)) = add_dir(map, x, y, &new_dir) {
                     queue.push_back(((nx, ny), new_dir, depth + 1));
                 }
@@ -180,7 +201,7 @@ fn render_path(
             State::SplitterHoriz => match dir {
                 Dir::E | Dir::W => {
                     // Continue on this path
-                    add_intensity(&mut frame, map, &mut cell_state, x, y, dir);
+                    add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir);
                     if let Some((nx, ny)) = add_dir(map, x, y, &dir) {
                         queue.push_back(((nx, ny), dir, depth + 1));
                     }
@@ -189,7 +210,7 @@ fn render_path(
                     // Split east
                     let dir1 = Dir::E;
 
-                    add_intensity(&mut frame, map, &mut cell_state, x, y, dir1);
+                    add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir1);
                     if let Some((nx, ny)) = add_dir(map, x, y, &dir1) {
                         queue.push_back(((nx, ny), dir1, depth + 1));
                     }
@@ -197,7 +218,7 @@ fn render_path(
                     // Split west
                     let dir2 = Dir::W;
 
-                    add_intensity(&mut frame, map, &mut cell_state, x, y, dir2);
+                    add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir2);
                     if let Some((nx, ny)) = add_dir(map, x, y, &dir2) {
                         queue.push_back(((nx, ny), dir2, depth + 1));
                     }
@@ -206,7 +227,7 @@ fn render_path(
             State::SplitterVert => match dir {
                 Dir::S | Dir::N => {
                     // Continue on this path
-                    add_intensity(&mut frame, map, &mut cell_state, x, y, dir);
+                    add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir);
                     if let Some((nx, ny)) = add_dir(map, x, y, &dir) {
                         queue.push_back(((nx, ny), dir, depth + 1));
                     }
@@ -215,7 +236,7 @@ fn render_path(
                     // Split north
                     let dir1 = Dir::N;
 
-                    add_intensity(&mut frame, map, &mut cell_state, x, y, dir1);
+                    add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir1);
                     if let Some((nx, ny)) = add_dir(map, x, y, &dir1) {
                         queue.push_back(((nx, ny), dir1, depth + 1));
                     }
@@ -223,7 +244,7 @@ fn render_path(
                     // Split south
                     let dir2 = Dir::S;
 
-                    add_intensity(&mut frame, map, &mut cell_state, x, y, dir2);
+                    add_intensity(&mut frame, map, &mut cell_state, blend, x, y, dir2);
                     if let Some((nx, ny)) = add_dir(map, x, y, &dir2) {
                         queue.push_back(((nx, ny), dir2, depth + 1));
                     }
@@ -232,17 +253,20 @@ fn render_path(
         };
     }
 
-    gif.draw_frame(frame.clone(), 1)?;
+    sink.draw_rgb_frame(&frame, 1)?;
+
+    sink.delay(1000)?;
 
-    gif.delay(1000)?;
+    sink.finish()?;
 
     Ok(())
 }
 
 fn add_intensity(
-    frame: &mut [Vec<u8>],
+    frame: &mut RgbCanvas,
     map: &[InputEnt],
     cell_state: &mut [Vec<CellState>],
+    blend: bool,
     x: usize,
     y: usize,
     dir: Dir,
@@ -254,21 +278,19 @@ fn add_intensity(
     }
     *state += 1;
 
-    draw_cell(frame, map, cell_state, x, y);
+    draw_cell(frame, map, cell_state, x, y, blend);
 
     true
 }
 
 fn draw_cell(
-    frame: &mut [Vec<u8>],
+    frame: &mut RgbCanvas,
     map: &[InputEnt],
     cell_state: &[Vec<CellState>],
     x: usize,
     y: usize,
+    blend: bool,
 ) {
-    let gx = x * CELLSIZE;
-    let gy = y * CELLSIZE;
-
     let state = &cell_state[y][x];
 
     let mut edges = Vec::new();
@@ -301,72 +323,46 @@ fn draw_cell(
         edges.push((intens, Dir::W));
     }
 
-    edges.sort();
-
-    for (intens, edge) in edges {
-        match edge {
-            Dir::N => draw_bmp(
-                frame,
-                gx,
-                gy,
-                7 + intens,
-                &[
-                    [0, 1, 1, 1, 0],
-                    [0, 1, 1, 1, 0],
-                    [0, 1, 1, 1, 0],
-                    [0, 0, 0, 0, 0],
-                    [0, 0, 0, 0, 0],
-                ],
-            ),
-            Dir::E => draw_bmp(
-                frame,
-                gx,
-                gy,
-                7 + intens,
-                &[
-                    [0, 0, 0, 0, 0],
-                    [0, 0, 1, 1, 1],
-                    [0, 0, 1, 1, 1],
-                    [0, 0, 1, 1, 1],
-                    [0, 0, 0, 0, 0],
-                ],
-            ),
-            Dir::S => draw_bmp(
-                frame,
-                gx,
-                gy,
-                7 + intens,
-                &[
-                    [0, 0, 0, 0, 0],
-                    [0, 0, 0, 0, 0],
-                    [0, 1, 1, 1, 0],
-                    [0, 1, 1, 1, 0],
-                    [0, 1, 1, 1, 0],
-                ],
-            ),
-            Dir::W => draw_bmp(
-                frame,
-                gx,
-                gy,
-                7 + intens,
-                &[
-                    [0, 0, 0, 0, 0],
-                    [1, 1, 1, 0, 0],
-                    [1, 1, 1, 0, 0],
-                    [1, 1, 1, 0, 0],
-                    [0, 0, 0, 0, 0],
-                ],
-            ),
+    if blend {
+        // Total horizontal and vertical intensity for each pixel
+        let mut totals = [[(0, 0); CELLSIZE]; CELLSIZE];
+
+        for (intens, edge) in edges {
+            for (row, mask_row) in totals.iter_mut().zip(beam_mask(edge)) {
+                for (total, bit) in row.iter_mut().zip(mask_row) {
+                    if bit != 0 {
+                        match edge {
+                            Dir::E | Dir::W => total.0 += intens,
+                            Dir::N | Dir::S => total.1 += intens,
+                        }
+                    }
+                }
+            }
+        }
+
+        let (gx, gy) = frame.cell(x, y);
+
+        for (py, row) in totals.iter().enumerate() {
+            for (px, &(horiz, vert)) in row.iter().enumerate() {
+                if horiz + vert > 0 {
+                    frame.set(gx + px, gy + py, blended(horiz, vert));
+                }
+            }
+        }
+    } else {
+        // Brightest beam on top
+        edges.sort();
+
+        for (intens, edge) in edges {
+            frame.blit_mask_cell(x, y, &beam_mask(edge), grey(intens));
         }
     }
 
     match map[y][x] {
         State::Empty => (),
-        State::MirrorNESW => draw_bmp(
-            frame,
-            gx,
-            gy,
-            1,
+        State::MirrorNESW => frame.blit_mask_cell(
+            x,
+            y,
             &[
                 [0, 0, 0, 1, 1],
                 [0, 0, 1, 1, 1],
@@ -374,12 +370,11 @@ fn draw_cell(
                 [1, 1, 1, 0, 0],
                 [1, 1, 0, 0, 0],
             ],
+            MIRROR,
         ),
-        State::MirrorNWSE => draw_bmp(
-            frame,
-            gx,
-            gy,
-            1,
+        State::MirrorNWSE => frame.blit_mask_cell(
+            x,
+            y,
             &[
                 [1, 1, 0, 0, 0],
                 [1, 1, 1, 0, 0],
@@ -387,12 +382,11 @@ fn draw_cell(
                 [0, 0, 1, 1, 1],
                 [0, 0, 0, 1, 1],
             ],
+            MIRROR,
         ),
-        State::SplitterHoriz => draw_bmp(
-            frame,
-            gx,
-            gy,
-            2,
+        State::SplitterHoriz => frame.blit_mask_cell(
+            x,
+            y,
             &[
                 [0, 0, 0, 0, 0],
                 [0, 1, 0, 1, 0],
@@ -400,12 +394,11 @@ fn draw_cell(
                 [0, 1, 0, 1, 0],
                 [0, 0, 0, 0, 0],
             ],
+            SPLITTER,
         ),
-        State::SplitterVert => draw_bmp(
-            frame,
-            gx,
-            gy,
-            2,
+        State::SplitterVert => frame.blit_mask_cell(
+            x,
+            y,
             &[
                 [0, 0, 1, 0, 0],
                 [0, 1, 1, 1, 0],
@@ -413,20 +406,69 @@ fn draw_cell(
                 [0, 1, 1, 1, 0],
                 [0, 0, 1, 0, 0],
             ],
+            SPLITTER,
         ),
     }
 }
 
-fn draw_bmp(frame: &mut [Vec<u8>], gx: usize, gy: usize, colour: u8, bmp: &[[u8; 5]; 5]) {
-    for (y, row) in bmp.iter().enumerate() {
-        for (x, col) in row.iter().enumerate() {
-            if *col != 0 {
-                frame[gy + y][gx + x] = colour;
-            }
-        }
+/// Returns the pixels of a cell lit by a beam on an edge
+fn beam_mask(edge: Dir) -> [[u8; CELLSIZE]; CELLSIZE] {
+    match edge {
+        Dir::N => [
+            [0, 1, 1, 1, 0],
+            [0, 1, 1, 1, 0],
+            [0, 1, 1, 1, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+        ],
+        Dir::E => [
+            [0, 0, 0, 0, 0],
+            [0, 0, 1, 1, 1],
+            [0, 0, 1, 1, 1],
+            [0, 0, 1, 1, 1],
+            [0, 0, 0, 0, 0],
+        ],
+        Dir::S => [
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+            [0, 1, 1, 1, 0],
+            [0, 1, 1, 1, 0],
+            [0, 1, 1, 1, 0],
+        ],
+        Dir::W => [
+            [0, 0, 0, 0, 0],
+            [1, 1, 1, 0, 0],
+            [1, 1, 1, 0, 0],
+            [1, 1, 1, 0, 0],
+            [0, 0, 0, 0, 0],
+        ],
     }
 }
 
+/// Palette shade of white for a beam intensity
+fn grey(intens: u8) -> [u8; 3] {
+    let component = (127 + (((intens - 1) as u32 * 128) / MAX_INTENSITY as u32)) as u8;
+
+    [component, component, component]
+}
+
+/// Colour of a pixel lit by beams with total horizontal and vertical intensities. Brighter with
+/// more intensity, tinted warm by horizontal beams and cool by vertical beams
+fn blended(horiz: u8, vert: u8) -> [u8; 3] {
+    let (horiz, vert) = (horiz as u32, vert as u32);
+    let total = horiz + vert;
+
+    let level = 95 + (160 * total) / (4 * MAX_INTENSITY as u32);
+    let tint = level / 3;
+
+    [
+        level - (tint * vert) / total,
+        level - tint / 2,
+        level - (tint * horiz) / total,
+    ]
+    .map(|c| c as u8)
+}
+
 fn energise(map: &[InputEnt], x: usize, y: usize, dir: Dir) -> u64 {
     let mut queue = VecDeque::new();
     let mut visited = HashSet::new();
@@ -0,0 +1,137 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{
+    geom::{Dir4, Pos},
+    grid::Grid,
+    input::Input,
+    search::dijkstra,
+    solution::Solution,
+};
+
+/// Day 17 solution
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: usize = 17;
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_grid(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(map: &Grid<u8>) -> u64 {
+    solve(map, PART1_MOVES)
+}
+
+fn part2(map: &Grid<u8>) -> u64 {
+    solve(map, PART2_MOVES)
+}
+
+/// Search node - position, direction of travel and number of moves made in that direction
+pub type Crucible = (Pos, Dir4, usize);
+
+/// Minimum number of moves a crucible must make in a direction before turning or stopping,
+/// and maximum number it can make
+#[derive(Debug, Clone, Copy)]
+pub struct Moves {
+    min: usize,
+    max: usize,
+}
+
+/// Moves for a normal crucible
+pub const PART1_MOVES: Moves = Moves { min: 1, max: 3 };
+
+/// Moves for an ultra crucible
+pub const PART2_MOVES: Moves = Moves { min: 4, max: 10 };
+
+impl Moves {
+    /// Returns the search start nodes - the top left corner heading east or south
+    pub fn starts(self) -> [Crucible; 2] {
+        let start = Pos::new(0, 0);
+
+        [(start, Dir4::E, 0), (start, Dir4::S, 0)]
+    }
+
+    /// Returns the next search nodes and the heat lost moving to each
+    pub fn successors<'a>(
+        self,
+        map: &'a Grid<u8>,
+        &(pos, dir, len): &Crucible,
+    ) -> impl Iterator<Item = (Crucible, u64)> + 'a {
+        // Go straight on if allowed this many forwards
+        let straight = (len < self.max).then_some((dir, len + 1));
+
+        // Turn left or right if allowed to turn
+        let turn = len >= self.min;
+        let left = turn.then_some((dir.turn_left(), 1));
+        let right = turn.then_some((dir.turn_right(), 1));
+
+        [straight, left, right]
+            .into_iter()
+            .flatten()
+            .filter_map(move |(dir, len)| {
+                map.step(pos, dir)
+                    .map(|pos| ((pos, dir, len), map[pos] as u64))
+            })
+    }
+
+    /// Returns true if the crucible has stopped in the bottom right corner
+    pub fn at_target(self, map: &Grid<u8>, &(pos, _, len): &Crucible) -> bool {
+        pos == Pos::new(map.width() - 1, map.height() - 1) && len >= self.min
+    }
+}
+
+fn solve(map: &Grid<u8>, moves: Moves) -> u64 {
+    dijkstra(
+        moves.starts(),
+        |node| moves.successors(map, node),
+        |node| moves.at_target(map, node),
+    )
+    .expect("No path found")
+    .cost
+}
+
+// Input parsing
+
+fn input_transform(c: char) -> u8 {
+    c as u8 - b'0'
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::grid::parse_test_grid;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(part1(&input), 102);
+        assert_eq!(part2(&input), 94);
+    }
+}
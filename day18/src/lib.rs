@@ -0,0 +1,125 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{
+    geom::{Dir4, Vec2},
+    input::Input,
+    polygon::{lattice_points, trace},
+    solution::Solution,
+};
+
+/// Day 18 solution
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: usize = 18;
+
+    type Input = (Vec<PlanStep>, Vec<PlanStep>);
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        Ok((
+            input.parse_vec(input_transform1)?,
+            input.parse_vec(input_transform2)?,
+        ))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (plan, _) = input;
+
+        calc_area(plan)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let (_, plan) = input;
+
+        calc_area(plan)
+    }
+}
+
+/// Calculate the area enclosed by the trench
+fn calc_area(plan: &[PlanStep]) -> i64 {
+    // Get the polygon vertices
+    let vertices = trace(
+        Vec2::default(),
+        plan.iter()
+            .map(|step| step.dir.offset() * step.amount as i64),
+    );
+
+    // Count the squares inside the trench and the trench itself
+    lattice_points(&vertices) as i64
+}
+
+// Input parsing
+
+/// Plan step
+pub struct PlanStep {
+    dir: Dir4,
+    amount: u64,
+}
+
+/// Transform for part 1
+fn input_transform1(line: String) -> PlanStep {
+    let mut split = line.split_ascii_whitespace();
+
+    let dir = split
+        .next()
+        .unwrap()
+        .parse::<Dir4>()
+        .expect("Bad direction");
+
+    let amount = split.next().unwrap().parse::<u64>().unwrap();
+
+    PlanStep { dir, amount }
+}
+
+/// Transform for part 2
+fn input_transform2(line: String) -> PlanStep {
+    let code = line.split('#').nth(1).unwrap().trim_end_matches(')');
+
+    let amount = u64::from_str_radix(&code[0..5], 16).unwrap();
+    let dir = match &code[5..6] {
+        "3" => Dir4::N,
+        "1" => Dir4::S,
+        "2" => Dir4::W,
+        "0" => Dir4::E,
+        _ => panic!("Bad direction"),
+    };
+
+    PlanStep { dir, amount }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::parse_test_vec;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_vec(EXAMPLE1, input_transform1).unwrap();
+        assert_eq!(calc_area(&input), 62);
+    }
+
+    #[test]
+    fn test2() {
+        let input = parse_test_vec(EXAMPLE1, input_transform2).unwrap();
+        assert_eq!(calc_area(&input), 952408144115);
+    }
+}
@@ -5,7 +5,7 @@ use std::{
     io::Write,
 };
 
-use aoc::input::parse_input_vec;
+use aoc::{geom::Dir4, input::parse_input_vec};
 
 fn main() -> Result<(), Box<dyn Error>> {
     // Get input
@@ -35,12 +35,9 @@ fn draw_trench(plan: &[PlanStep], file: &str) -> Result<(), Box<dyn Error>> {
     let mut max_y = i64::MIN;
 
     for e in plan {
-        match e.dir {
-            PlanDir::Up => y -= e.amount as i64,
-            PlanDir::Down => y += e.amount as i64,
-            PlanDir::Left => x -= e.amount as i64,
-            PlanDir::Right => x += e.amount as i64,
-        }
+        let v = e.dir.offset() * e.amount as i64;
+        x += v.x;
+        y += v.y;
 
         min_x = min(min_x, x);
         min_y = min(min_y, y);
@@ -70,12 +67,9 @@ fn draw_trench(plan: &[PlanStep], file: &str) -> Result<(), Box<dyn Error>> {
     let mut y = 0i64;
 
     for e in plan {
-        match e.dir {
-            PlanDir::Up => y -= e.amount as i64,
-            PlanDir::Down => y += e.amount as i64,
-            PlanDir::Left => x -= e.amount as i64,
-            PlanDir::Right => x += e.amount as i64,
-        }
+        let v = e.dir.offset() * e.amount as i64;
+        x += v.x;
+        y += v.y;
         file.write_fmt(format_args!(" L {x} {y}"))?;
     }
 
@@ -92,30 +86,19 @@ fn draw_trench(plan: &[PlanStep], file: &str) -> Result<(), Box<dyn Error>> {
 
 /// Plan step
 struct PlanStep {
-    dir: PlanDir,
+    dir: Dir4,
     amount: u64,
 }
 
-/// Dig direction
-#[derive(PartialEq)]
-enum PlanDir {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 /// Transform for part 1
 fn input_transform1(line: String) -> PlanStep {
     let mut split = line.split_ascii_whitespace();
 
-    let dir = match split.next().unwrap() {
-        "U" => PlanDir::Up,
-        "D" => PlanDir::Down,
-        "L" => PlanDir::Left,
-        "R" => PlanDir::Right,
-        _ => panic!("Bad direction"),
-    };
+    let dir = split
+        .next()
+        .unwrap()
+        .parse::<Dir4>()
+        .expect("Bad direction");
 
     let amount = split.next().unwrap().parse::<u64>().unwrap();
 
@@ -128,10 +111,10 @@ fn input_transform2(line: String) -> PlanStep {
 
     let amount = u64::from_str_radix(&code[0..5], 16).unwrap();
     let dir = match &code[5..6] {
-        "3" => PlanDir::Up,
-        "1" => PlanDir::Down,
-        "2" => PlanDir::Left,
-        "0" => PlanDir::Right,
+        "3" => Dir4::N,
+        "1" => Dir4::S,
+        "2" => Dir4::W,
+        "0" => Dir4::E,
         _ => panic!("Bad direction"),
     };
 
@@ -0,0 +1,438 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+};
+
+use aoc::{
+    input::{try_map_lines, Input, LineError, LineResultExt, LinesError},
+    interval::IntervalBox,
+    solution::Solution,
+};
+
+/// Day 19 solution
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: usize = 19;
+
+    type Input = (HashMap<String, Rule>, Vec<Part>);
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.try_parse_header_body(parse_rules, parse_parts)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (rules, parts) = input;
+
+        part1(rules, parts)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let (rules, _) = input;
+
+        part2(rules)
+    }
+}
+
+fn part1(rules: &HashMap<String, Rule>, parts: &[Part]) -> u64 {
+    // Iterate parts
+    parts
+        .iter()
+        .map(|part| {
+            // Get first rule
+            let mut rule = rules.get("in").expect("'in' rule not found");
+
+            // Condition loop
+            loop {
+                // Iterate conditions in the rule
+                let action = rule.conditions.iter().find_map(|cond| {
+                    // Get the value from the part
+                    let part_value = cond.term.get(part);
+
+                    // Test the value
+                    if match cond.op {
+                        Op::Lt => part_value < cond.value,
+                        Op::Gt => part_value > cond.value,
+                    } {
+                        // Passed - execute condition
+                        Some(&cond.then)
+                    } else {
+                        None
+                    }
+                });
+
+                // If no condition triggered then execute the else condition
+                let action = match action {
+                    Some(action) => action,
+                    None => &rule.otherwise,
+                };
+
+                // Process the action
+                match action {
+                    Action::Accept => {
+                        // Accept the part
+                        break part.sum();
+                    }
+                    Action::Reject => {
+                        // Reject the part
+                        break 0;
+                    }
+                    Action::Goto(rule_name) => {
+                        // Go to another rule
+                        rule = rules
+                            .get(rule_name)
+                            .unwrap_or_else(|| panic!("Rule '{rule_name}' not found"));
+                    }
+                }
+            }
+        })
+        .sum()
+}
+
+fn part2(rules: &HashMap<String, Rule>) -> u64 {
+    // Accepted part ranges
+    let mut accepted = Vec::new();
+
+    // Process the first rule set
+    process_rule(
+        rules,
+        "in",
+        IntervalBox::new(std::array::from_fn(|_| 1..4001)),
+        &mut accepted,
+    );
+
+    // Sum up part combinations
+    accepted.iter().map(|a| a.volume::<u64>()).sum()
+}
+
+/// Ranges for each attribute, indexed by Term
+type Ranges = IntervalBox<u16, 4>;
+
+/// Process a rule
+fn process_rule(
+    rules: &HashMap<String, Rule>,
+    rule_name: &str,
+    mut ranges: Ranges,
+    accepted: &mut Vec<Ranges>,
+) {
+    // Get the rule from the rule map
+    let rule = rules
+        .get(rule_name)
+        .unwrap_or_else(|| panic!("Rule '{rule_name}' not found"));
+
+    // Process each condition recursively
+    rule.conditions
+        .iter()
+        .for_each(|cond| process_condition(rules, cond, &mut ranges, accepted));
+
+    // Process the else action
+    process_action(rules, &rule.otherwise, ranges, accepted);
+}
+
+/// Process a comdition
+fn process_condition(
+    rules: &HashMap<String, Rule>,
+    cond: &Condition,
+    ranges: &mut Ranges,
+    accepted: &mut Vec<Ranges>,
+) {
+    let term = cond.term as usize;
+
+    // Split the ranges in to those matching the condition and the remainder
+    let (this_ranges, rest) = match cond.op {
+        Op::Gt => {
+            let (rest, this_ranges) = ranges.split_at(term, cond.value + 1);
+            (this_ranges, rest)
+        }
+        Op::Lt => ranges.split_at(term, cond.value),
+    };
+
+    *ranges = rest;
+
+    // Process the action with the split off range
+    process_action(rules, &cond.then, this_ranges, accepted);
+}
+
+/// Process an action
+fn process_action(
+    rules: &HashMap<String, Rule>,
+    action: &Action,
+    ranges: Ranges,
+    accepted: &mut Vec<Ranges>,
+) {
+    match action {
+        Action::Accept => accepted.push(ranges),
+        Action::Reject => (),
+        Action::Goto(rule_name) => process_rule(rules, rule_name, ranges, accepted),
+    }
+}
+
+// Input parsing
+
+/// Rule with conditions and else clause
+#[derive(Debug)]
+pub struct Rule {
+    conditions: Vec<Condition>,
+    otherwise: Action,
+}
+
+/// Condition with test and action if true
+#[derive(Debug)]
+struct Condition {
+    term: Term,
+    op: Op,
+    value: u16,
+    then: Action,
+}
+
+/// Product terms
+#[derive(Debug, Clone, Copy)]
+enum Term {
+    X = 0,
+    M,
+    A,
+    S,
+}
+
+impl Term {
+    /// Create product term from string
+    fn new(string: &str) -> Result<Self, LineError> {
+        match string {
+            "x" => Ok(Term::X),
+            "m" => Ok(Term::M),
+            "a" => Ok(Term::A),
+            "s" => Ok(Term::S),
+            term => Err(LineError::new(format!("Invalid term '{term}'"))),
+        }
+    }
+
+    /// Gets the term value from a product
+    fn get(&self, part: &Part) -> u16 {
+        part.values[*self as usize]
+    }
+}
+
+/// Test operators
+#[derive(Debug)]
+enum Op {
+    Gt,
+    Lt,
+}
+
+impl Op {
+    /// Create operator from string
+    fn new(op: &str) -> Result<Self, LineError> {
+        match op {
+            "<" => Ok(Op::Lt),
+            ">" => Ok(Op::Gt),
+            op => Err(LineError::new(format!("Invalid operator '{op}'"))),
+        }
+    }
+}
+
+/// Actions
+#[derive(Debug)]
+enum Action {
+    Accept,
+    Reject,
+    Goto(String),
+}
+
+impl Action {
+    /// Create new action from a string
+    fn new(string: &str) -> Self {
+        match string {
+            "A" => Action::Accept,
+            "R" => Action::Reject,
+            target => Action::Goto(target.to_string()),
+        }
+    }
+}
+
+/// Part with terms indexed by Term
+#[derive(Debug, Default)]
+pub struct Part {
+    values: [u16; 4],
+}
+
+impl Part {
+    /// Sums a product terms
+    fn sum(&self) -> u64 {
+        self.values.iter().map(|v| *v as u64).sum()
+    }
+}
+
+/// Parses the rules section to a rule hash map
+fn parse_rules<'a>(lines: &[&'a str]) -> Result<HashMap<String, Rule>, LinesError<'a>> {
+    let mut errors = LinesError::new();
+
+    let rules = try_map_lines(lines, &mut errors, |line| {
+        parse_rule(line).map(|rule| (line, rule))
+    });
+
+    // Check every rule jumped to exists
+    let names = rules
+        .iter()
+        .map(|(_, (name, _))| *name)
+        .collect::<HashSet<_>>();
+
+    if !names.contains("in") {
+        errors.push(lines.first().copied().unwrap_or(""), "No 'in' rule found");
+    }
+
+    for (line, (_, rule)) in &rules {
+        let targets = rule
+            .conditions
+            .iter()
+            .map(|c| &c.then)
+            .chain([&rule.otherwise]);
+
+        for target in targets {
+            if let Action::Goto(target) = target {
+                if !names.contains(target.as_str()) {
+                    errors.push(line, format!("Rule '{target}' not found"));
+                }
+            }
+        }
+    }
+
+    errors.or_value(
+        rules
+            .into_iter()
+            .map(|(_, (name, rule))| (name.to_string(), rule))
+            .collect(),
+    )
+}
+
+/// Parses a rule line to its name and rule
+fn parse_rule(line: &str) -> Result<(&str, Rule), LineError> {
+    let (name, condition_str) = line
+        .split_once('{')
+        .ok_or_else(|| LineError::new("Condition clause not found"))?;
+
+    let condition_str = condition_str
+        .strip_suffix('}')
+        .ok_or_else(|| LineError::new("Expected '}' at end of rule"))?;
+
+    let mut conditions = Vec::new();
+    let mut otherwise = None;
+
+    for cond_clause in condition_str.split(',') {
+        if let Some((cond_str, then)) = cond_clause.split_once(':') {
+            // Condition
+            let (Some(term), Some(op), Some(value)) =
+                (cond_str.get(0..1), cond_str.get(1..2), cond_str.get(2..))
+            else {
+                return Err(LineError::new("Invalid condition").within(line, cond_str));
+            };
+
+            conditions.push(Condition {
+                term: Term::new(term).within(line, term)?,
+                op: Op::new(op).within(line, op)?,
+                value: value.parse::<u16>().within(line, value)?,
+                then: Action::new(then),
+            })
+        } else {
+            // Else clause
+            otherwise = Some(Action::new(cond_clause));
+        }
+    }
+
+    Ok((
+        name,
+        Rule {
+            conditions,
+            otherwise: otherwise.ok_or_else(|| LineError::new("No condition else found"))?,
+        },
+    ))
+}
+
+/// Parses the parts section to a part vector
+fn parse_parts<'a>(lines: &[&'a str]) -> Result<Vec<Part>, LinesError<'a>> {
+    let mut errors = LinesError::new();
+
+    let parts = try_map_lines(lines, &mut errors, |line| {
+        let mut part = Part::default();
+
+        let attrs = line
+            .strip_prefix('{')
+            .and_then(|l| l.strip_suffix('}'))
+            .ok_or_else(|| LineError::new("Expected part in braces"))?;
+
+        for attr in attrs.split(',') {
+            let (term, value) = attr
+                .split_once('=')
+                .ok_or_else(|| LineError::new("Part value not found").within(line, attr))?;
+
+            let term = Term::new(term).within(line, term)?;
+            let value = value.parse::<u16>().within(line, value)?;
+
+            part.values[term as usize] = value;
+        }
+
+        Ok::<_, LineError>(part)
+    });
+
+    errors.or_value(parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::try_parse_test_header_body;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn test_bad_input() {
+        let error = try_parse_test_header_body(
+            "in{a<2006:qkq,m>x:A,R}\npx{y>1:A,R}\n\n{x=787,m=2655,a=1222}\n{x=1,q=2}",
+            parse_rules,
+            parse_parts,
+        )
+        .err()
+        .unwrap()
+        .to_string();
+
+        assert!(error.contains("error: invalid digit found in string\n --> <test>:1:17"));
+        assert!(error.contains("error: Invalid term 'y'\n --> <test>:2:4"));
+        assert!(error.contains("error: Invalid term 'q'\n --> <test>:5:6"));
+        assert!(error.contains("error: No 'in' rule found\n --> <test>:1:1"));
+        assert!(error.ends_with("4 lines failed to parse"));
+
+        let error = try_parse_test_header_body("in{x<1:qkq,R}\n\n{x=1}", parse_rules, parse_parts)
+            .err()
+            .unwrap()
+            .to_string();
+
+        assert!(error.starts_with("error: Rule 'qkq' not found\n --> <test>:1:1"));
+    }
+
+    #[test]
+    fn test1() {
+        let (rules, parts) =
+            try_parse_test_header_body(EXAMPLE1, parse_rules, parse_parts).unwrap();
+
+        assert_eq!(part1(&rules, &parts), 19114);
+        assert_eq!(part2(&rules), 167409079868000);
+    }
+}
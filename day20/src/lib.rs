@@ -0,0 +1,400 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::Display,
+};
+
+use aoc::{cycle::brent, input::Input, math::lcm_all, solution::Solution};
+
+/// Day 20 solution
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: usize = 20;
+
+    type Input = (HashMap<String, Component>, Vec<(String, String)>);
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        let lines = input.parse_vec(input_transform)?;
+
+        Ok(parse_input(&lines))
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        let (components, _) = input;
+
+        part1(components.clone())
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        let (components, connections) = input;
+
+        part2(components, connections)
+    }
+}
+
+fn part1(mut components: HashMap<String, Component>) -> u64 {
+    let mut low = 0;
+    let mut high = 0;
+
+    let mut pulse_queue = VecDeque::new();
+
+    for _ in 0..1000 {
+        // Push the button
+        push_button(&mut components, &mut pulse_queue);
+
+        // Loop queue entries
+        while let Some(pulse) = pulse_queue.pop_front() {
+            // Count pulses
+            match pulse.pulse {
+                Pulse::Low => low += 1,
+                Pulse::High => high += 1,
+            }
+
+            // Process the pulse
+            process_pulse(&pulse, &mut components, &mut pulse_queue)
+        }
+    }
+
+    low * high
+}
+
+fn part2(components: &HashMap<String, Component>, connections: &[(String, String)]) -> u64 {
+    // Component just before rx should be conjunction
+    let last = connections
+        .iter()
+        .find_map(|(a, b)| if b == "rx" { Some(a.as_str()) } else { None })
+        .expect("Unable to find last component");
+
+    // Each broadcaster output drives a separate counter circuit feeding the last conjunction
+    let inputs = match components.get("broadcaster") {
+        Some(Component::Broadcaster(broadcaster)) => &broadcaster.cout,
+        _ => panic!("Can't find broadcaster"),
+    };
+
+    let periods = inputs.iter().map(|input| {
+        let circuit = sub_circuit(components, connections, input, last);
+
+        // Find the number of button presses before the counter resets
+        let cycle = brent(&circuit, |circuit| {
+            let mut circuit = circuit.clone();
+            push_sub_circuit(&mut circuit, input, last);
+            circuit
+        });
+
+        // Check the counter only sends a high pulse on the last press of its first cycle.
+        // It then does so once every cycle as long as the cycle starts before that press
+        let mut circuit = circuit;
+        let highs = (1..=cycle.prefix + cycle.period)
+            .filter(|_| push_sub_circuit(&mut circuit, input, last))
+            .collect::<Vec<_>>();
+
+        if cycle.prefix >= cycle.period || highs != [cycle.period] {
+            panic!("Counter from {input} does not signal {last} once per cycle");
+        }
+
+        cycle.period as u64
+    });
+
+    // Find lowest common multiplier for cycle counts and return
+    lcm_all(periods).expect("LCM overflows")
+}
+
+/// Finds the components reachable from a broadcaster output without passing through the
+/// last component
+fn sub_circuit(
+    components: &HashMap<String, Component>,
+    connections: &[(String, String)],
+    input: &str,
+    last: &str,
+) -> HashMap<String, Component> {
+    let mut found = HashSet::from([input]);
+    let mut queue = VecDeque::from([input]);
+
+    while let Some(name) = queue.pop_front() {
+        for (_, b) in connections.iter().filter(|(a, _)| a == name) {
+            if b != last && found.insert(b) {
+                queue.push_back(b);
+            }
+        }
+    }
+
+    found
+        .into_iter()
+        .map(|name| (name.to_string(), components[name].clone()))
+        .collect()
+}
+
+/// Pushes the button for a sub-circuit, sending a low pulse to its input only.
+/// Returns true if a high pulse was sent to the last component
+fn push_sub_circuit(circuit: &mut HashMap<String, Component>, input: &str, last: &str) -> bool {
+    let mut pulse_queue = VecDeque::new();
+    let mut high = false;
+
+    send_pulse(&mut pulse_queue, "broadcaster", input, Pulse::Low);
+
+    while let Some(pulse) = pulse_queue.pop_front() {
+        if pulse.target == last {
+            high |= pulse.pulse == Pulse::High;
+        } else {
+            process_pulse(&pulse, circuit, &mut pulse_queue);
+        }
+    }
+
+    high
+}
+
+#[derive(Debug)]
+struct WirePulse {
+    source: String,
+    target: String,
+    pulse: Pulse,
+}
+
+/// Push the button!
+fn push_button(components: &mut HashMap<String, Component>, pulse_queue: &mut VecDeque<WirePulse>) {
+    // Get button component
+    match components.get("button") {
+        Some(Component::Button(button)) => {
+            // Send a low pulse to the connected component
+            send_pulse(pulse_queue, "button", &button.cout, Pulse::Low);
+        }
+        _ => panic!("Can't find button"),
+    }
+}
+
+/// Process a pulse
+fn process_pulse(
+    pulse: &WirePulse,
+    components: &mut HashMap<String, Component>,
+    pulse_queue: &mut VecDeque<WirePulse>,
+) {
+    // Get the target component
+    let comp = components
+        .get_mut(&pulse.target)
+        .unwrap_or_else(|| panic!("Can't find component {}", pulse.target));
+
+    match comp {
+        Component::Broadcaster(broadcaster) => {
+            // Send a pulse to all connected components
+            broadcaster.cout.iter().for_each(|target| {
+                send_pulse(pulse_queue, &pulse.target, target, pulse.pulse);
+            })
+        }
+        Component::Button(_) => panic!("Button in loop?"),
+        Component::FlipFlop(flipflop) => {
+            // Only act on low pulse
+            if pulse.pulse == Pulse::Low {
+                // Flip the state
+                flipflop.state = !flipflop.state;
+
+                // Work out type of pulse to send
+                let out_pulse = if flipflop.state {
+                    Pulse::High
+                } else {
+                    Pulse::Low
+                };
+
+                // Send the pulse to all connected components
+                flipflop.cout.iter().for_each(|target| {
+                    send_pulse(pulse_queue, &pulse.target, target, out_pulse);
+                })
+            }
+        }
+        Component::Conjunction(conj) => {
+            // Get pointer to the input
+            *conj
+                .state
+                .get_mut(&pulse.source)
+                .expect("Conjunction pulse from unknown source") = pulse.pulse;
+
+            // Work out output pulse type (Low if all inputs high, otherwise High)
+            let out_pulse = if conj.state.iter().all(|(_, p)| *p == Pulse::High) {
+                Pulse::Low
+            } else {
+                Pulse::High
+            };
+
+            // Send the pulse to all connected components
+            conj.cout.iter().for_each(|target| {
+                send_pulse(pulse_queue, &pulse.target, target, out_pulse);
+            })
+        }
+        Component::Output(output) => output.pulses.push(pulse.pulse),
+    }
+}
+
+/// Send a pulse from source to target
+fn send_pulse(pulse_queue: &mut VecDeque<WirePulse>, source: &str, target: &str, pulse: Pulse) {
+    pulse_queue.push_back(WirePulse {
+        source: source.to_string(),
+        target: target.to_string(),
+        pulse,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Component {
+    Button(Button),
+    Broadcaster(Broadcaster),
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+    Output(Output),
+}
+
+/// Sends a pulse to the broadcaster
+#[derive(Debug, Clone, PartialEq)]
+pub struct Button {
+    cout: String,
+}
+
+/// Sends in pulse to all outputs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Broadcaster {
+    cout: Vec<String>,
+}
+
+/// (%) Switches state on low pulse and sends low if off and high if on
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlipFlop {
+    state: bool,
+    cout: Vec<String>,
+}
+
+/// (&) Sends low if all inputs are high, else low
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conjunction {
+    state: HashMap<String, Pulse>,
+    cout: Vec<String>,
+}
+
+/// Remembers received pulses
+#[derive(Debug, Clone, PartialEq)]
+pub struct Output {
+    pulses: Vec<Pulse>,
+}
+
+// Input parsing
+
+type InputEnt = String;
+
+fn input_transform(line: String) -> InputEnt {
+    line
+}
+
+fn parse_input(input: &[String]) -> (HashMap<String, Component>, Vec<(String, String)>) {
+    let mut components = HashMap::new();
+    let mut connections = Vec::new();
+
+    for line in input {
+        let mut split1 = line.split(" -> ");
+        let comp = split1.next().unwrap();
+        let conns = split1.next().unwrap();
+        let conns = conns.split(", ").map(String::from).collect::<Vec<_>>();
+
+        let (name, comp) = if &comp[0..1] == "&" {
+            (
+                &comp[1..],
+                Component::Conjunction(Conjunction {
+                    state: HashMap::new(),
+                    cout: conns.clone(),
+                }),
+            )
+        } else if &comp[0..1] == "%" {
+            (
+                &comp[1..],
+                Component::FlipFlop(FlipFlop {
+                    state: false,
+                    cout: conns.clone(),
+                }),
+            )
+        } else if comp == "broadcaster" {
+            (
+                comp,
+                Component::Broadcaster(Broadcaster {
+                    cout: conns.clone(),
+                }),
+            )
+        } else {
+            panic!("Invalid component")
+        };
+
+        conns
+            .into_iter()
+            .for_each(|conn| connections.push((name.to_string(), conn)));
+
+        components.insert(name.to_string(), comp);
+    }
+
+    // Add button
+    components.insert(
+        "button".to_string(),
+        Component::Button(Button {
+            cout: "broadcaster".to_string(),
+        }),
+    );
+
+    connections.push(("button".to_string(), "broadcaster".to_string()));
+
+    // Add outputs if required
+    connections.iter().map(|(_, b)| b).for_each(|b| {
+        components
+            .entry(b.to_string())
+            .or_insert_with(|| Component::Output(Output { pulses: Vec::new() }));
+    });
+
+    // Resolve conjunctions
+    components.iter_mut().for_each(|(name, comp)| {
+        if let Component::Conjunction(conj) = comp {
+            connections
+                .iter()
+                .filter(|(_, b)| b == name)
+                .for_each(|(a, _)| {
+                    conj.state.insert(a.to_string(), Pulse::Low);
+                })
+        }
+    });
+
+    (components, connections)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::parse_test_vec;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const EXAMPLE2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+        let (components, _) = parse_input(&input);
+        assert_eq!(part1(components), 32000000);
+    }
+
+    #[test]
+    fn test2() {
+        let input = parse_test_vec(EXAMPLE2, input_transform).unwrap();
+        let (components, _) = parse_input(&input);
+        assert_eq!(part1(components), 11687500);
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt::Display,
+};
+
+use aoc::{grid::Grid, input::Input, math::Quadratic, solution::Solution};
+
+/// Day 21 solution
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: usize = 21;
+
+    type Input = Grid<Square>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_grid(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input, 64)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input, 26501365)
+    }
+}
+
+fn part1(map: &Grid<Square>, steps: usize) -> u64 {
+    let mut queue = VecDeque::new();
+    let mut visited = HashMap::new();
+
+    let (x, y) = find_start(map);
+
+    queue.push_back((x, y, 0));
+    visited.insert((x, y), 0);
+
+    while let Some((x, y, cur_steps)) = queue.pop_front() {
+        for (nx, ny) in map.neighbours4(x, y) {
+            if map[(nx, ny)] != Square::Rock && !visited.contains_key(&(nx, ny)) {
+                if cur_steps < steps {
+                    queue.push_back((nx, ny, cur_steps + 1));
+                }
+                visited.insert((nx, ny), cur_steps + 1);
+            }
+        }
+    }
+
+    visited.iter().filter(|(_, s)| **s & 0x01 == 0).count() as u64
+}
+
+fn part2(map: &Grid<Square>, steps: usize) -> u64 {
+    let dim = map.height();
+    let div = (steps / dim) as i64;
+    let remainder = steps % dim;
+
+    // Get 3 points in the quadratic equation
+    let s0 = remainder;
+    let s1 = s0 + dim;
+    let s2 = s1 + dim;
+
+    let p0 = p2solve(map, s0);
+    let p1 = p2solve(map, s1);
+    let p2 = p2solve(map, s2);
+
+    // Solve the quadratic equation through f(0), f(1) and f(2)
+    let quad = Quadratic::through(p0, p1, p2).expect("Plot counts are not quadratic");
+
+    quad.eval(div).expect("Plot count overflows") as u64
+}
+
+fn find_start(map: &Grid<Square>) -> (usize, usize) {
+    map.find(|s| *s == Square::Start)
+        .expect("Unable to find start")
+}
+
+fn p2solve(map: &Grid<Square>, steps: usize) -> i64 {
+    let mut queue = VecDeque::new();
+    let mut visited = HashMap::new();
+
+    let (sx, sy) = find_start(map);
+
+    // Positions are unbounded, with the map repeating infinitely in all directions
+    queue.push_back((sx as isize, sy as isize, 0));
+    visited.insert((sx as isize, sy as isize), 0);
+
+    while let Some((x, y, cur_steps)) = queue.pop_front() {
+        // North, east, south, west
+        for (nx, ny) in [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)] {
+            if *map.get_wrapping(nx, ny) != Square::Rock && !visited.contains_key(&(nx, ny)) {
+                if cur_steps >= steps {
+                    continue;
+                }
+
+                queue.push_back((nx, ny, cur_steps + 1));
+                visited.insert((nx, ny), cur_steps + 1);
+            }
+        }
+    }
+
+    visited
+        .iter()
+        .filter(|(_, s)| **s & 1 == steps & 0x01)
+        .count() as i64
+}
+
+// Input parsing
+
+#[derive(Debug, PartialEq)]
+pub enum Square {
+    Start,
+    Plot,
+    Rock,
+}
+
+fn input_transform(c: char) -> Square {
+    match c {
+        'S' => Square::Start,
+        '.' => Square::Plot,
+        '#' => Square::Rock,
+        c => panic!("Invalid char {c}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::grid::parse_test_grid;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+
+        assert_eq!(part1(&input, 6), 16);
+        assert_eq!(p2solve(&input, 6), 16);
+        assert_eq!(p2solve(&input, 10), 50);
+        assert_eq!(p2solve(&input, 50), 1594);
+        assert_eq!(p2solve(&input, 100), 6536);
+        assert_eq!(p2solve(&input, 500), 167004);
+        assert_eq!(p2solve(&input, 1000), 668697);
+        // assert_eq!(p2solve(&input, 5000), 16733044);
+    }
+}
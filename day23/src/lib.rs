@@ -0,0 +1,274 @@
+use std::{
+    cmp::max,
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt::Display,
+};
+
+use aoc::{
+    geom::{Dir4, Pos},
+    grid::Grid,
+    input::Input,
+    solution::Solution,
+};
+
+/// Day 23 solution
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: usize = 23;
+
+    type Input = Grid<Tile>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_grid(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(map: &Grid<Tile>) -> u64 {
+    let mut longest = 0;
+
+    let (start, end) = find_exits(map);
+
+    let mut queue = VecDeque::new();
+    let mut visited = HashMap::new();
+
+    queue.push_back((start, Dir4::S, 0));
+
+    while let Some((pos, dir, steps)) = queue.pop_back() {
+        if pos == end {
+            longest = max(longest, steps);
+            continue;
+        }
+
+        let steps = steps + 1;
+
+        // Try each direction apart from going back
+        for next_dir in Dir4::ALL.into_iter().filter(|&d| d != dir.reverse()) {
+            let Some(next) = map.step(pos, next_dir) else {
+                continue;
+            };
+
+            if !map[next].enterable(next_dir) {
+                continue;
+            }
+
+            match visited.entry(next) {
+                Entry::Occupied(mut v) => {
+                    if *v.get() >= steps {
+                        continue;
+                    }
+
+                    *v.get_mut() = steps;
+                }
+                Entry::Vacant(v) => {
+                    v.insert(steps);
+                }
+            }
+
+            queue.push_back((next, next_dir, steps));
+        }
+    }
+
+    longest
+}
+
+fn part2(map: &Grid<Tile>) -> u64 {
+    // Find nodes
+    let (start, end) = find_exits(map);
+
+    let mut nodes = HashSet::new();
+
+    nodes.insert(start);
+    nodes.insert(end);
+
+    for (pos, tile) in map.enumerate() {
+        if matches!(tile, Tile::Forest) {
+            continue;
+        }
+
+        let pos = Pos::from(pos);
+
+        if open_neighbours(map, pos).count() > 2 {
+            nodes.insert(pos);
+        }
+    }
+
+    // Build edges
+    let mut edges: HashMap<Pos, Vec<(Pos, u64)>> = HashMap::new();
+
+    for &node in nodes.iter() {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+
+        queue.push_back((node, 0));
+        visited.insert(node);
+
+        while let Some((pos, steps)) = queue.pop_front() {
+            if pos != node && nodes.contains(&pos) {
+                // On a node - add edge
+                edges.entry(node).or_default().push((pos, steps));
+
+                continue;
+            }
+
+            for next in open_neighbours(map, pos) {
+                if visited.insert(next) {
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+    }
+
+    let state = State {
+        pos: start,
+        steps: 0,
+        visited: HashSet::new(),
+    };
+
+    find_longest(state, end, &edges)
+}
+
+/// Returns the neighbouring positions which aren't forest
+fn open_neighbours(map: &Grid<Tile>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    Dir4::ALL
+        .into_iter()
+        .filter_map(move |dir| map.step(pos, dir))
+        .filter(|&next| !matches!(map[next], Tile::Forest))
+}
+
+struct State {
+    pos: Pos,
+    steps: u64,
+    visited: HashSet<Pos>,
+}
+
+fn find_longest(mut state: State, end: Pos, edges: &HashMap<Pos, Vec<(Pos, u64)>>) -> u64 {
+    state.visited.insert(state.pos);
+
+    edges
+        .get(&state.pos)
+        .unwrap()
+        .iter()
+        .filter_map(|&(pos, steps)| {
+            if state.visited.contains(&pos) {
+                None
+            } else {
+                let steps = state.steps + steps;
+
+                Some(if pos == end {
+                    steps
+                } else {
+                    find_longest(
+                        State {
+                            pos,
+                            steps,
+                            visited: state.visited.clone(),
+                        },
+                        end,
+                        edges,
+                    )
+                })
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn find_exits(map: &Grid<Tile>) -> (Pos, Pos) {
+    let exit_in_row = |y| {
+        let x = map
+            .row(y)
+            .iter()
+            .position(|t| matches!(t, Tile::Path))
+            .unwrap();
+
+        Pos::new(x, y)
+    };
+
+    (exit_in_row(0), exit_in_row(map.height() - 1))
+}
+
+// Input parsing
+
+pub enum Tile {
+    Path,
+    Forest,
+    SlopeN,
+    SlopeW,
+    SlopeE,
+    SlopeS,
+}
+
+impl Tile {
+    /// Returns true if the tile can be entered moving in a direction
+    fn enterable(&self, dir: Dir4) -> bool {
+        matches!(
+            (self, dir),
+            (Tile::Path, _)
+                | (Tile::SlopeN, Dir4::N)
+                | (Tile::SlopeE, Dir4::E)
+                | (Tile::SlopeS, Dir4::S)
+                | (Tile::SlopeW, Dir4::W)
+        )
+    }
+}
+
+fn input_transform(c: char) -> Tile {
+    match c {
+        '.' => Tile::Path,
+        '#' => Tile::Forest,
+        '^' => Tile::SlopeN,
+        '<' => Tile::SlopeW,
+        '>' => Tile::SlopeE,
+        'v' => Tile::SlopeS,
+        _ => panic!("Invalid tile"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::grid::parse_test_grid;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_grid(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(part1(&input), 94);
+        assert_eq!(part2(&input), 154);
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 };
 
 use aoc::{
-    gif::{Gif, IdenticalAction},
+    gif::{Canvas, Gif, IdenticalAction},
     input::parse_input_vec,
 };
 
@@ -404,7 +404,13 @@ fn create_gif(
     nodes: Option<HashSet<(usize, usize)>>,
 ) -> Result<(), Box<dyn Error>> {
     // Create GIF
-    let palette: [[u8; 3]; 4] = [[0, 0, 0], [64, 255, 64], [255, 255, 64], [128, 64, 255]];
+    let palette: [[u8; 3]; 5] = [
+        [0, 0, 0],
+        [64, 255, 64],
+        [255, 255, 64],
+        [128, 64, 255],
+        [0, 0, 0], // Transparent
+    ];
 
     let mut gif = Gif::new(
         file,
@@ -413,46 +419,43 @@ fn create_gif(
         map.len() as u16 * 5,
         1,
         1,
-    )?;
+    )?
+    .with_transparent_deltas(4);
 
     // Draw base frame
     let mut frame = base_frame(map, &gif, nodes);
 
-    gif.draw_frame(frame.clone(), 0)?;
+    gif.draw_frame(&frame, 0)?;
 
     // Animate path
     for (i, (x, y)) in path.iter().enumerate() {
-        let gx = x * 5;
-        let gy = y * 5;
+        let (gx, gy) = frame.cell(*x, *y);
+        gif.mark_dirty(gx, gy, 5, 5);
 
-        for y in 0..5 {
-            for x in 0..5 {
-                if frame[gy + y][gx + x] == 0 {
-                    frame[gy + y][gx + x] = 3;
+        for y in gy..gy + 5 {
+            for x in gx..gx + 5 {
+                if frame.get(x, y) == 0 {
+                    frame.set(x, y, 3);
                 }
             }
         }
 
         if i % 5 == 0 {
-            gif.draw_frame(frame.clone(), 1)?;
+            gif.draw_frame(&frame, 1)?;
         }
     }
 
-    gif.draw_frame_identical_check(frame, 1000, IdenticalAction::Delay)?;
+    gif.draw_frame_identical_check(&frame, 1000, IdenticalAction::Delay)?;
 
     Ok(())
 }
 
-fn base_frame(map: &[MapRow], gif: &Gif, nodes: Option<HashSet<(usize, usize)>>) -> Vec<Vec<u8>> {
-    let mut frame = gif.empty_frame();
+fn base_frame(map: &[MapRow], gif: &Gif, nodes: Option<HashSet<(usize, usize)>>) -> Canvas {
+    let mut frame = gif.empty_frame().with_cell_size(5, 5);
 
     (0..map.len()).for_each(|y| {
-        let gy = y * 5;
-
-        for x in 0..map[y].len() {
-            let gx = x * 5;
-
-            let bmp = if matches!(map[y][x], Tile::Forest) {
+        for (x, tile) in map[y].iter().enumerate() {
+            let bmp = if matches!(tile, Tile::Forest) {
                 [
                     [0, 1, 1, 1, 0],
                     [1, 1, 1, 1, 1],
@@ -479,7 +482,7 @@ fn base_frame(map: &[MapRow], gif: &Gif, nodes: Option<HashSet<(usize, usize)>>)
                     ]
                 }
             } else {
-                match map[y][x] {
+                match tile {
                     Tile::Path => [
                         [0, 0, 0, 0, 0],
                         [0, 0, 0, 0, 0],
@@ -519,13 +522,7 @@ fn base_frame(map: &[MapRow], gif: &Gif, nodes: Option<HashSet<(usize, usize)>>)
                 }
             };
 
-            for (y, br) in bmp.iter().enumerate() {
-                for (x, bc) in br.iter().enumerate() {
-                    if *bc != 0 {
-                        frame[gy + y][gx + x] = *bc;
-                    }
-                }
-            }
+            frame.blit_cell(x, y, &bmp, Some(0));
         }
     });
 
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use aoc::{input::Input, solution::Solution};
+use day24::{report, Day24};
+
+/// Prints how the paths of each pair of hailstones in the input cross in part 1
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = Day24::parse(&Input::new(Day24::DAY)?)?;
+
+    print!("{}", report(&input));
+
+    Ok(())
+}
@@ -0,0 +1,567 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{input::Input, solution::Solution};
+
+/// Day 24 solution
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: usize = 24;
+
+    type Input = Vec<HailStone>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_vec(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input, LOWER, UPPER)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+/// Lower bound of the part 1 test area
+const LOWER: i64 = 200_000_000_000_000;
+
+/// Upper bound of the part 1 test area
+const UPPER: i64 = 400_000_000_000_000;
+
+fn part1(input: &[HailStone], lower: i64, upper: i64) -> u64 {
+    crossings(input, lower, upper)
+        .filter(|(_, _, crossing)| *crossing == Crossing::Inside)
+        .count() as u64
+}
+
+/// Where the paths of two hailstones cross in the x-y plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Crossing {
+    /// Paths cross in the future within the test area
+    Inside,
+    /// Paths cross in the future outside the test area
+    Outside,
+    /// Paths crossed in the past for one or both hailstones
+    Past,
+    /// Paths never cross
+    Parallel,
+}
+
+/// Exact point where two paths cross, as (x, y) numerators over a common denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Point {
+    x: i128,
+    y: i128,
+    denom: i128,
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let denom = self.denom as f64;
+
+        write!(
+            f,
+            "x={:.3}, y={:.3}",
+            self.x as f64 / denom,
+            self.y as f64 / denom
+        )
+    }
+}
+
+/// Returns the indexes and crossing of each pair of hailstones
+fn crossings(
+    input: &[HailStone],
+    lower: i64,
+    upper: i64,
+) -> impl Iterator<Item = (usize, usize, Crossing)> + '_ {
+    input.iter().enumerate().flat_map(move |(i, h1)| {
+        input
+            .iter()
+            .enumerate()
+            .skip(i + 1)
+            .map(move |(j, h2)| (i, j, crossing(h1, h2, lower, upper).0))
+    })
+}
+
+/// Classifies where the paths of two hailstones cross in the x-y plane, also returning the
+/// crossing point if the paths are not parallel. All arithmetic is exact
+fn crossing(h1: &HailStone, h2: &HailStone, lower: i64, upper: i64) -> (Crossing, Option<Point>) {
+    let cross = |ax: i128, ay: i128, bx: i128, by: i128| ax * by - ay * bx;
+
+    let (x1, y1, vx1, vy1) = (h1.x as i128, h1.y as i128, h1.vx as i128, h1.vy as i128);
+    let (x2, y2, vx2, vy2) = (h2.x as i128, h2.y as i128, h2.vx as i128, h2.vy as i128);
+
+    // Solve p1 + v1 t1 = p2 + v2 t2 by crossing both sides with v2 and v1
+    let mut denom = cross(vx1, vy1, vx2, vy2);
+
+    if denom == 0 {
+        return (Crossing::Parallel, None);
+    }
+
+    let (dx, dy) = (x2 - x1, y2 - y1);
+    let mut t1 = cross(dx, dy, vx2, vy2);
+    let mut t2 = cross(dx, dy, vx1, vy1);
+
+    // Keep the denominator positive so comparisons don't flip
+    if denom < 0 {
+        (denom, t1, t2) = (-denom, -t1, -t2);
+    }
+
+    let point = Point {
+        x: x1 * denom + vx1 * t1,
+        y: y1 * denom + vy1 * t1,
+        denom,
+    };
+
+    let (lower, upper) = (lower as i128 * denom, upper as i128 * denom);
+    let within = |n: i128| n >= lower && n <= upper;
+
+    let class = if t1 < 0 || t2 < 0 {
+        Crossing::Past
+    } else if within(point.x) && within(point.y) {
+        Crossing::Inside
+    } else {
+        Crossing::Outside
+    };
+
+    (class, Some(point))
+}
+
+/// Describes how the paths of each pair of hailstones cross the part 1 test area. Run with
+/// `cargo run --release -p day24 --example report`
+pub fn report(input: &[HailStone]) -> String {
+    crossing_report(input, LOWER, UPPER)
+}
+
+/// Describes the crossing of each pair of hailstones
+fn crossing_report(input: &[HailStone], lower: i64, upper: i64) -> String {
+    let mut report = String::new();
+
+    for (i, h1) in input.iter().enumerate() {
+        for (j, h2) in input.iter().enumerate().skip(i + 1) {
+            let description = match crossing(h1, h2, lower, upper) {
+                (Crossing::Inside, Some(point)) => format!("cross inside the test area ({point})"),
+                (Crossing::Outside, Some(point)) => {
+                    format!("cross outside the test area ({point})")
+                }
+                (Crossing::Past, _) => "crossed in the past".to_string(),
+                _ => "are parallel".to_string(),
+            };
+
+            report += &format!("Hailstones {i} and {j}: paths {description}\n");
+        }
+    }
+
+    report
+}
+
+fn part2(input: &[HailStone]) -> i128 {
+    let rock = find_rock(input).expect("No rock trajectory hits every hailstone");
+
+    rock.pos.iter().sum()
+}
+
+type Vec3 = [i128; 3];
+
+/// Position and velocity of the thrown rock
+#[derive(Debug, PartialEq, Eq)]
+struct Rock {
+    pos: Vec3,
+    vel: Vec3,
+}
+
+/// Finds the rock trajectory which hits every hailstone with exact integer arithmetic.
+/// Relative to the first hailstone the rock passes through the origin, so its path lies in the
+/// plane through the origin containing each other hailstone's relative path. Two of those
+/// planes intersect along the rock's relative direction, which gives the collision times
+fn find_rock(input: &[HailStone]) -> Option<Rock> {
+    let (first, others) = input.split_first()?;
+
+    let relative = others
+        .iter()
+        .map(|h| {
+            let pos = sub(h.pos(), first.pos())?;
+            let vel = sub(h.vel(), first.vel())?;
+            let normal = cross(pos, vel)?;
+
+            Some((h, pos, vel, normal))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    for (i, (h1, pos1, vel1, normal1)) in relative.iter().enumerate() {
+        for (h2, pos2, vel2, normal2) in relative.iter().skip(i + 1) {
+            // Planes are the same or overflow
+            let Some(dir) = cross(*normal1, *normal2).and_then(primitive) else {
+                continue;
+            };
+
+            let (Some(t1), Some(t2)) = (meet_time(*pos1, *vel1, dir), meet_time(*pos2, *vel2, dir))
+            else {
+                continue;
+            };
+
+            if let Some(rock) = rock_through(h1.at(t1), t1, h2.at(t2), t2) {
+                if input.iter().all(|h| rock.hits(h)) {
+                    return Some(rock);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the time at which a path (relative to the first hailstone) meets the line through
+/// the origin in direction dir, if it meets it at a whole time
+fn meet_time(pos: Vec3, vel: Vec3, dir: Vec3) -> Option<i128> {
+    // pos + vel t is parallel to dir, ie (pos x dir) + t (vel x dir) = 0
+    let a = cross(pos, dir)?;
+    let b = cross(vel, dir)?;
+
+    let denom = dot(b, b)?;
+
+    if denom == 0 {
+        // Path is parallel to the rock
+        return None;
+    }
+
+    let numer = dot(a, b)?.checked_neg()?;
+
+    if numer % denom != 0 {
+        return None;
+    }
+
+    let t = numer / denom;
+
+    // Check the path really meets the line
+    (0..3)
+        .all(|i| b[i].checked_mul(t).and_then(|bt| bt.checked_add(a[i])) == Some(0))
+        .then_some(t)
+}
+
+/// Returns the rock trajectory passing through two points at two times, if the velocity
+/// is whole
+fn rock_through(p1: Option<Vec3>, t1: i128, p2: Option<Vec3>, t2: i128) -> Option<Rock> {
+    let (p1, p2) = (p1?, p2?);
+    let dt = t2.checked_sub(t1)?;
+
+    if dt == 0 {
+        return None;
+    }
+
+    let diff = sub(p2, p1)?;
+
+    if diff.iter().any(|d| d % dt != 0) {
+        return None;
+    }
+
+    let vel = diff.map(|d| d / dt);
+    let pos = sub(p1, scale(vel, t1)?)?;
+
+    Some(Rock { pos, vel })
+}
+
+impl Rock {
+    /// Returns true if the rock hits a hailstone at or after time zero
+    fn hits(&self, h: &HailStone) -> bool {
+        let (Some(pos), Some(vel)) = (sub(h.pos(), self.pos), sub(h.vel(), self.vel)) else {
+            return false;
+        };
+
+        // A hailstone moving with the rock is only hit if it starts in the same place
+        if vel == [0; 3] {
+            return pos == [0; 3];
+        }
+
+        // Relative position must be parallel to and closing along the relative velocity
+        cross(pos, vel) == Some([0; 3]) && dot(pos, vel).is_some_and(|d| d <= 0)
+    }
+}
+
+fn sub(a: Vec3, b: Vec3) -> Option<Vec3> {
+    Some([
+        a[0].checked_sub(b[0])?,
+        a[1].checked_sub(b[1])?,
+        a[2].checked_sub(b[2])?,
+    ])
+}
+
+fn scale(a: Vec3, n: i128) -> Option<Vec3> {
+    Some([
+        a[0].checked_mul(n)?,
+        a[1].checked_mul(n)?,
+        a[2].checked_mul(n)?,
+    ])
+}
+
+fn dot(a: Vec3, b: Vec3) -> Option<i128> {
+    a[0].checked_mul(b[0])?
+        .checked_add(a[1].checked_mul(b[1])?)?
+        .checked_add(a[2].checked_mul(b[2])?)
+}
+
+fn cross(a: Vec3, b: Vec3) -> Option<Vec3> {
+    let det = |i: usize, j: usize| a[i].checked_mul(b[j])?.checked_sub(a[j].checked_mul(b[i])?);
+
+    Some([det(1, 2)?, det(2, 0)?, det(0, 1)?])
+}
+
+/// Divides a vector by the gcd of its components, returning None for the zero vector
+fn primitive(a: Vec3) -> Option<Vec3> {
+    let gcd = a.iter().fold(0u128, |g, n| gcd(g, n.unsigned_abs()));
+
+    (gcd != 0).then(|| a.map(|n| n / gcd as i128))
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Solves part 2 with the Z3 theorem prover, used to cross-check the exact solver
+#[cfg(all(test, feature = "z3"))]
+fn part2_z3(input: &[HailStone]) -> u64 {
+    use z3::ast::Ast;
+
+    let cfg = z3::Config::new();
+    let context = z3::Context::new(&cfg);
+    let solver = z3::Solver::new(&context);
+
+    let x = z3::ast::Int::new_const(&context, "x");
+    let y = z3::ast::Int::new_const(&context, "y");
+    let z = z3::ast::Int::new_const(&context, "z");
+
+    let vx = z3::ast::Int::new_const(&context, "vx");
+    let vy = z3::ast::Int::new_const(&context, "vy");
+    let vz = z3::ast::Int::new_const(&context, "vz");
+
+    for (i, hs) in input.iter().take(3).enumerate() {
+        let a = z3::ast::Int::from_i64(&context, hs.x);
+        let b = z3::ast::Int::from_i64(&context, hs.y);
+        let c = z3::ast::Int::from_i64(&context, hs.z);
+
+        let va = z3::ast::Int::from_i64(&context, hs.vx);
+        let vb = z3::ast::Int::from_i64(&context, hs.vy);
+        let vc = z3::ast::Int::from_i64(&context, hs.vz);
+
+        let t = z3::ast::Int::new_const(&context, format!("t{i}"));
+
+        solver.assert(&t.gt(&z3::ast::Int::from_i64(&context, 0)));
+        solver.assert(&(x.clone() + vx.clone() * t.clone())._eq(&(a + va * t.clone())));
+        solver.assert(&(y.clone() + vy.clone() * t.clone())._eq(&(b + vb * t.clone())));
+        solver.assert(&(z.clone() + vz.clone() * t.clone())._eq(&(c + vc * t.clone())));
+    }
+
+    let result = if solver.check() == z3::SatResult::Sat {
+        let Some(m) = solver.get_model() else {
+            panic!("Failed to solve!");
+        };
+        m.eval(&(x + y + z), true).unwrap()
+    } else {
+        panic!("Not satisfied")
+    };
+
+    result.as_u64().unwrap()
+}
+
+// Input parsing
+
+pub struct HailStone {
+    x: i64,
+    y: i64,
+    z: i64,
+    vx: i64,
+    vy: i64,
+    vz: i64,
+}
+
+impl HailStone {
+    fn pos(&self) -> Vec3 {
+        [self.x, self.y, self.z].map(i128::from)
+    }
+
+    fn vel(&self) -> Vec3 {
+        [self.vx, self.vy, self.vz].map(i128::from)
+    }
+
+    /// Returns the position of the hailstone at a time
+    fn at(&self, t: i128) -> Option<Vec3> {
+        let moved = scale(self.vel(), t)?;
+
+        Some([
+            self.pos()[0].checked_add(moved[0])?,
+            self.pos()[1].checked_add(moved[1])?,
+            self.pos()[2].checked_add(moved[2])?,
+        ])
+    }
+}
+
+fn input_transform(line: String) -> HailStone {
+    let mut split = line.split(" @ ");
+
+    let pos = split
+        .next()
+        .unwrap()
+        .split(", ")
+        .map(|s| s.trim().parse::<i64>().unwrap())
+        .collect::<Vec<_>>();
+
+    let vel = split
+        .next()
+        .unwrap()
+        .split(", ")
+        .map(|s| s.trim().parse::<i64>().unwrap())
+        .collect::<Vec<_>>();
+
+    HailStone {
+        x: pos[0],
+        y: pos[1],
+        z: pos[2],
+        vx: vel[0],
+        vy: vel[1],
+        vz: vel[2],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::input::parse_test_vec;
+
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn test1() {
+        let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(part1(&input, 7, 27), 2);
+        assert_eq!(
+            crossings(&input, 7, 27)
+                .map(|(_, _, crossing)| crossing)
+                .collect::<Vec<_>>(),
+            vec![
+                Crossing::Inside,
+                Crossing::Inside,
+                Crossing::Outside,
+                Crossing::Past,
+                Crossing::Parallel,
+                Crossing::Outside,
+                Crossing::Past,
+                Crossing::Outside,
+                Crossing::Past,
+                Crossing::Past,
+            ]
+        );
+        assert_eq!(part2(&input), 47);
+        assert_eq!(
+            find_rock(&input),
+            Some(Rock {
+                pos: [24, 13, 10],
+                vel: [-3, 1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+        let report = crossing_report(&input, 7, 27);
+
+        assert_eq!(
+            report.lines().next(),
+            Some("Hailstones 0 and 1: paths cross inside the test area (x=14.333, y=15.333)")
+        );
+        assert_eq!(
+            report.lines().nth(4),
+            Some("Hailstones 1 and 2: paths are parallel")
+        );
+        assert_eq!(report.lines().count(), 10);
+    }
+
+    #[test]
+    fn test_boundary() {
+        // Paths cross exactly on the corner of the test area, and just outside it at
+        // puzzle sized coordinates
+        let input = parse_test_vec(
+            "\
+0, 0, 0 @ 1, 1, 0
+20, 0, 0 @ -1, 1, 0
+400000000000001, 0, 0 @ 0, 1, 0
+0, 400000000000000, 0 @ 1, 0, 0",
+            input_transform,
+        )
+        .unwrap();
+
+        assert_eq!(crossing(&input[0], &input[1], 10, 20).0, Crossing::Inside);
+        assert_eq!(crossing(&input[0], &input[1], 11, 20).0, Crossing::Outside);
+        assert_eq!(
+            crossing(
+                &input[2],
+                &input[3],
+                200_000_000_000_000,
+                400_000_000_000_000
+            )
+            .0,
+            Crossing::Outside
+        );
+    }
+
+    #[test]
+    fn test_no_rock() {
+        // Parallel hailstones which can't all be hit
+        let input = parse_test_vec(
+            "\
+0, 0, 0 @ 1, 0, 0
+0, 1, 0 @ 1, 0, 0
+5, 0, 3 @ 0, 1, 0
+9, 2, 7 @ 0, 0, 1",
+            input_transform,
+        )
+        .unwrap();
+
+        assert_eq!(find_rock(&input), None);
+    }
+
+    #[test]
+    fn test_hits_same_velocity() {
+        let input = parse_test_vec(
+            "\
+1, 2, 3 @ 1, 0, -1
+4, 2, 3 @ 1, 0, -1
+2, 2, 3 @ 0, 0, -1",
+            input_transform,
+        )
+        .unwrap();
+
+        let rock = Rock {
+            pos: [1, 2, 3],
+            vel: [1, 0, -1],
+        };
+
+        // Moving with the rock from the same and a different position
+        assert!(rock.hits(&input[0]));
+        assert!(!rock.hits(&input[1]));
+
+        // Hit at time one
+        assert!(rock.hits(&input[2]));
+    }
+
+    #[cfg(feature = "z3")]
+    #[test]
+    fn test_z3() {
+        let input = parse_test_vec(EXAMPLE1, input_transform).unwrap();
+        assert_eq!(part2_z3(&input) as i128, part2(&input));
+
+        let input = Day24::parse(&Input::new(24).unwrap()).unwrap();
+        assert_eq!(part2_z3(&input) as i128, part2(&input));
+    }
+}
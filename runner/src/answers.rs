@@ -0,0 +1,139 @@
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use aoc::input::read_input_file;
+use serde::Deserialize;
+
+/// File in the inputs directory containing the expected answers
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers for each day
+pub struct ExpectedAnswers {
+    days: HashMap<String, DayAnswers>,
+}
+
+/// Expected answers for a single day
+#[derive(Debug, Deserialize)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Expected answer which may be written as an integer or a string
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Int(i64),
+    Str(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{i}"),
+            Answer::Str(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Outcome of checking an answer against the expected answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Check::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl ExpectedAnswers {
+    /// Loads the expected answers from the inputs directory
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let content = read_input_file(ANSWERS_FILE)
+            .map_err(|e| format!("Unable to read {ANSWERS_FILE}: {e}"))?;
+
+        Self::parse(&content)
+    }
+
+    /// Parses expected answers from TOML
+    fn parse(content: &str) -> Result<Self, Box<dyn Error>> {
+        let days = toml::from_str(content).map_err(|e| format!("{ANSWERS_FILE}: {e}"))?;
+
+        Ok(Self { days })
+    }
+
+    /// Checks an answer for a given day, input set and part against the expected answer.
+    /// Answers for a named input set are in a table named `dayNN-<name>`
+    pub fn check(&self, day: usize, set: Option<&str>, part: usize, answer: &str) -> Check {
+        let key = match set {
+            Some(name) => format!("day{day:02}-{name}"),
+            None => format!("day{day:02}"),
+        };
+
+        let expected = self.days.get(&key).and_then(|d| match part {
+            1 => d.part1.as_ref(),
+            2 => d.part2.as_ref(),
+            _ => panic!("Invalid part {part}"),
+        });
+
+        match expected {
+            None => Check::Unknown,
+            Some(expected) => {
+                let expected = expected.to_string();
+
+                if expected == answer {
+                    Check::Pass
+                } else {
+                    Check::Fail(expected)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers = ExpectedAnswers::parse(
+            "\
+[day01]
+part1 = 142
+part2 = \"abc\"
+
+[day02]
+part1 = 8
+
+[day02-alice]
+part1 = 9",
+        )
+        .unwrap();
+
+        assert_eq!(answers.check(1, None, 1, "142"), Check::Pass);
+        assert_eq!(
+            answers.check(1, None, 1, "141"),
+            Check::Fail("142".to_string())
+        );
+        assert_eq!(answers.check(1, None, 2, "abc"), Check::Pass);
+        assert_eq!(answers.check(2, None, 1, "8"), Check::Pass);
+        assert_eq!(answers.check(2, None, 2, "2286"), Check::Unknown);
+        assert_eq!(answers.check(3, None, 1, "0"), Check::Unknown);
+
+        // Named input sets
+        assert_eq!(answers.check(2, Some("alice"), 1, "9"), Check::Pass);
+        assert_eq!(
+            answers.check(2, Some("alice"), 1, "8"),
+            Check::Fail("9".to_string())
+        );
+        assert_eq!(answers.check(1, Some("alice"), 1, "142"), Check::Unknown);
+    }
+}
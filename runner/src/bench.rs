@@ -0,0 +1,264 @@
+use std::{
+    env::consts::{ARCH, OS},
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aoc::{solution::Answers, timing::PhaseStats};
+
+/// Directory benchmark results are written to
+const STATS_DIR: &str = "stats";
+
+/// Output format for benchmark results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Returns the file extension for the format
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Csv => "csv",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format '{s}' (expected json or csv)")),
+        }
+    }
+}
+
+/// Benchmark results for a day
+pub struct DayBench {
+    pub day: usize,
+    /// Input set, or None for the default input
+    pub set: Option<String>,
+    pub runs: usize,
+    pub answers: Answers,
+    pub stats: PhaseStats,
+}
+
+impl DayBench {
+    /// Writes the results to the stats directory in the given format, returning the file path
+    pub fn write(&self, format: Format) -> io::Result<PathBuf> {
+        self.write_in(Path::new(STATS_DIR), format)
+    }
+
+    /// Writes the results to a directory in the given format, returning the file path
+    fn write_in(&self, dir: &Path, format: Format) -> io::Result<PathBuf> {
+        let set = match &self.set {
+            Some(name) => format!("-{name}"),
+            None => String::new(),
+        };
+
+        let path = dir.join(format!(
+            "day{:02}{set}-{OS}.{}",
+            self.day,
+            format.extension()
+        ));
+
+        let content = match format {
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        };
+
+        fs::create_dir_all(dir)?;
+        fs::write(&path, content)?;
+
+        Ok(path)
+    }
+
+    /// Returns the name of the input set
+    fn input(&self) -> &str {
+        self.set.as_deref().unwrap_or("default")
+    }
+
+    /// Formats the results as JSON
+    fn to_json(&self) -> String {
+        let mut json = String::new();
+
+        writeln!(json, "{{").unwrap();
+        writeln!(json, "  \"day\": {},", self.day).unwrap();
+        writeln!(json, "  \"input\": {},", json_string(self.input())).unwrap();
+        writeln!(json, "  \"runs\": {},", self.runs).unwrap();
+        writeln!(json, "  \"os\": \"{OS}\",").unwrap();
+        writeln!(json, "  \"arch\": \"{ARCH}\",").unwrap();
+        writeln!(json, "  \"part1\": {},", json_string(&self.answers.part1)).unwrap();
+        writeln!(json, "  \"part2\": {},", json_string(&self.answers.part2)).unwrap();
+        writeln!(json, "  \"timings_ns\": {{").unwrap();
+
+        let phases = self.stats.phases();
+
+        for (i, (phase, stats)) in phases.iter().enumerate() {
+            writeln!(
+                json,
+                "    \"{phase}\": {{ \"median\": {}, \"min\": {}, \"max\": {} }}{}",
+                stats.median.as_nanos(),
+                stats.min.as_nanos(),
+                stats.max.as_nanos(),
+                if i < phases.len() - 1 { "," } else { "" }
+            )
+            .unwrap();
+        }
+
+        writeln!(json, "  }}").unwrap();
+        writeln!(json, "}}").unwrap();
+
+        json
+    }
+
+    /// Formats the results as CSV with one row per phase
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("day,input,runs,os,arch,phase,median_ns,min_ns,max_ns\n");
+
+        for (phase, stats) in self.stats.phases() {
+            writeln!(
+                csv,
+                "{},{},{},{OS},{ARCH},{phase},{},{},{}",
+                self.day,
+                self.input(),
+                self.runs,
+                stats.median.as_nanos(),
+                stats.min.as_nanos(),
+                stats.max.as_nanos()
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+}
+
+/// Quotes and escapes a string for JSON output
+fn json_string(s: &str) -> String {
+    let mut result = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Duration};
+
+    use aoc::timing::Stats;
+
+    use super::*;
+
+    fn day_bench(set: Option<&str>) -> DayBench {
+        let stats = |n| Stats {
+            median: Duration::from_nanos(n * 2),
+            min: Duration::from_nanos(n),
+            max: Duration::from_nanos(n * 3),
+        };
+
+        DayBench {
+            day: 7,
+            set: set.map(String::from),
+            runs: 5,
+            answers: Answers {
+                part1: "12\"3".to_string(),
+                part2: "a\\b\n".to_string(),
+            },
+            stats: PhaseStats {
+                load: stats(1),
+                parse: stats(10),
+                part1: stats(100),
+                part2: stats(1000),
+            },
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&day_bench(Some("alice")).to_json()).unwrap();
+
+        assert_eq!(json["day"], 7);
+        assert_eq!(json["input"], "alice");
+        assert_eq!(json["runs"], 5);
+        assert_eq!(json["os"], OS);
+        assert_eq!(json["arch"], ARCH);
+        assert_eq!(json["part1"], "12\"3");
+        assert_eq!(json["part2"], "a\\b\n");
+
+        for (phase, n) in [("load", 1), ("parse", 10), ("part1", 100), ("part2", 1000)] {
+            let timings = &json["timings_ns"][phase];
+
+            assert_eq!(timings["median"], n * 2);
+            assert_eq!(timings["min"], n);
+            assert_eq!(timings["max"], n * 3);
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = day_bench(None).to_csv();
+        let mut lines = csv.lines().map(|l| l.split(',').collect::<Vec<_>>());
+
+        assert_eq!(
+            lines.next().unwrap(),
+            [
+                "day",
+                "input",
+                "runs",
+                "os",
+                "arch",
+                "phase",
+                "median_ns",
+                "min_ns",
+                "max_ns"
+            ]
+        );
+
+        for (phase, n) in [("load", 1), ("parse", 10), ("part1", 100), ("part2", 1000)] {
+            let (median, min, max) = ((n * 2).to_string(), n.to_string(), (n * 3).to_string());
+
+            assert_eq!(
+                lines.next().unwrap(),
+                ["7", "default", "5", OS, ARCH, phase, &median, &min, &max]
+            );
+        }
+
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+
+        let bench = day_bench(Some("bob"));
+        let path = bench.write_in(&dir, Format::Csv).unwrap();
+
+        assert_eq!(path, dir.join(format!("day07-bob-{OS}.csv")));
+        assert_eq!(fs::read_to_string(&path).unwrap(), bench.to_csv());
+
+        let path = day_bench(None).write_in(&dir, Format::Json).unwrap();
+
+        assert_eq!(path, dir.join(format!("day07-{OS}.json")));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
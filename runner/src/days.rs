@@ -0,0 +1,58 @@
+use std::error::Error;
+
+use aoc::{
+    input::Resolver,
+    solution::{bench, solve_with, Answers},
+    timing::PhaseStats,
+};
+
+/// Function to load the input located by a resolver, parse and solve a day
+pub type SolveFn = fn(&Resolver) -> Result<Answers, Box<dyn Error>>;
+
+/// Function to solve a day a number of times with the input located by a resolver, timing
+/// each phase
+pub type BenchFn = fn(&Resolver, usize) -> Result<(Answers, PhaseStats), Box<dyn Error>>;
+
+/// Entry points for a day's solution
+pub struct Day {
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            solve: solve_with::<$solution>,
+            bench: bench::<$solution>,
+        }
+    };
+}
+
+/// Entry points for each day, indexed by day number - 1. New days are appended by `aoc new`
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+    day!(day07::Day07),
+    day!(day08::Day08),
+    day!(day09::Day09),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day19::Day19),
+    day!(day20::Day20),
+    day!(day21::Day21),
+    day!(day22::Day22),
+    day!(day23::Day23),
+    day!(day24::Day24),
+    day!(day25::Day25),
+];
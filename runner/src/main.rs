@@ -0,0 +1,562 @@
+use std::{env, error::Error, fs, ops::RangeInclusive, path::Path, process::ExitCode};
+
+use aoc::{
+    input::{CurlFetcher, Resolver, DEFAULT_YEAR, FIRST_YEAR},
+    solution::Answers,
+};
+
+use crate::{
+    answers::{Check, ExpectedAnswers},
+    bench::{DayBench, Format},
+    days::DAYS,
+    table::Table,
+};
+
+mod answers;
+mod bench;
+mod days;
+mod new;
+mod table;
+
+const USAGE: &str = "\
+Usage: aoc <command>
+
+Commands:
+  run <days> [options]    Run solutions for the given days (N, A..B, A..=B or all)
+  verify [days] [options] Check answers against inputs/answers.toml (default all days)
+  bench <days> [options]  Time input loading, parsing and each part, writing results to stats/
+  examples <day> <page>   Extract example inputs from a saved puzzle page to inputs/exampleNN-M.txt
+  new <day> [--vis]       Create the crate for a new day (and visualisation crate) from the templates
+  fetch <days> [options]  Download inputs which are not already in the input directory
+
+Run and verify options:
+  --input <name>          Use the dayNN-<name>.txt input set
+  --all-inputs            Run every input set found for each day
+
+Fetch options:
+  --year <year>           Puzzle year (default 2023)
+  --user <name>           Download to dayNN-<name>.txt using token-<name>.txt
+  --token <file>          File containing the session token (default token.txt)
+
+Bench options:
+  --input <name>          Use the dayNN-<name>.txt input set
+  --runs <n>              Number of runs to take the median of (default 10)
+  --format <json|csv>     Output file format (default json)";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", days, ref options @ ..] => parse_days(days).and_then(|days| {
+            let sets = InputSets::parse(options)?;
+            Ok(run(days, &sets))
+        }),
+        ["verify", days, ref options @ ..] if !days.starts_with("--") => {
+            parse_days(days).and_then(|days| {
+                let sets = InputSets::parse(options)?;
+                verify(days, &sets)
+            })
+        }
+        ["verify", ref options @ ..] => {
+            InputSets::parse(options).and_then(|sets| verify(1..=DAYS.len(), &sets))
+        }
+        ["bench", days, ref options @ ..] => parse_days(days).and_then(|days| {
+            let options = BenchOptions::parse(options)?;
+            Ok(bench(days, &options))
+        }),
+        ["fetch", days, ref options @ ..] => parse_days(days).and_then(|days| {
+            let options = FetchOptions::parse(options)?;
+            fetch(days, &options)
+        }),
+        ["examples", day, page] => parse_day(day).and_then(|day| examples(day, page)),
+        ["new", day] => parse_new_day(day).and_then(|day| new_day(day, false)),
+        ["new", day, "--vis"] => parse_new_day(day).and_then(|day| new_day(day, true)),
+        _ => Err(format!("Invalid arguments\n\n{USAGE}")),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Input sets to run solutions against
+enum InputSets {
+    /// The default dayNN.txt input
+    Default,
+    /// The dayNN-<name>.txt input
+    Named(String),
+    /// Every input set found for each day
+    All,
+}
+
+impl InputSets {
+    /// Parses input set options from the command line
+    fn parse(args: &[&str]) -> Result<Self, String> {
+        match args {
+            [] => Ok(InputSets::Default),
+            ["--input", name] => Ok(InputSets::Named(name.to_string())),
+            ["--all-inputs"] => Ok(InputSets::All),
+            _ => Err(format!("Invalid options\n\n{USAGE}")),
+        }
+    }
+
+    /// Returns the input sets to run for a day. None is the default input set
+    fn for_day(&self, day: usize) -> Vec<Option<String>> {
+        match self {
+            InputSets::Default => vec![None],
+            InputSets::Named(name) => vec![Some(name.clone())],
+            InputSets::All => {
+                let sets = Resolver::new().input_sets(day);
+
+                if sets.is_empty() {
+                    // Report the missing default input
+                    vec![None]
+                } else {
+                    sets
+                }
+            }
+        }
+    }
+
+    /// Returns the results table headings, including an input column unless running
+    /// the default input set only
+    fn headings(&self) -> Vec<&'static str> {
+        match self {
+            InputSets::Default => vec!["Day", "Part 1", "Part 2"],
+            _ => vec!["Day", "Input", "Part 1", "Part 2"],
+        }
+    }
+
+    /// Returns the leading cells of a results table row
+    fn row(&self, day: usize, set: Option<&str>) -> Vec<String> {
+        match self {
+            InputSets::Default => vec![day.to_string()],
+            _ => vec![day.to_string(), set.unwrap_or("default").to_string()],
+        }
+    }
+}
+
+/// Returns the resolver for an input set
+fn resolver(set: Option<&str>) -> Resolver {
+    match set {
+        Some(name) => Resolver::new().user(name),
+        None => Resolver::new(),
+    }
+}
+
+/// Solves a day with an input set
+fn solve(day: usize, set: Option<&str>) -> Result<Answers, Box<dyn Error>> {
+    (DAYS[day - 1].solve)(&resolver(set))
+}
+
+/// Runs the solutions for a range of days and prints the answers
+fn run(days: RangeInclusive<usize>, sets: &InputSets) -> ExitCode {
+    let mut table = Table::new(&sets.headings());
+    let mut errors = Errors::default();
+
+    for day in days {
+        for set in sets.for_day(day) {
+            let set = set.as_deref();
+            let mut row = sets.row(day, set);
+
+            match solve(day, set) {
+                Ok(answers) => row.extend([answers.part1, answers.part2]),
+                Err(e) => row.extend([errors.add(day, set, e), String::new()]),
+            }
+
+            table.add_row(row);
+        }
+    }
+
+    table.print();
+    errors.print();
+
+    if !errors.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Runs the solutions for a range of days and checks the answers against the expected answers
+fn verify(days: RangeInclusive<usize>, sets: &InputSets) -> Result<ExitCode, String> {
+    let expected = ExpectedAnswers::load().map_err(|e| e.to_string())?;
+
+    let mut table = Table::new(&sets.headings());
+    let mut errors = Errors::default();
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    for day in days {
+        for set in sets.for_day(day) {
+            let set = set.as_deref();
+            let mut row = sets.row(day, set);
+
+            match solve(day, set) {
+                Ok(answers) => {
+                    for (part, answer) in [(1, answers.part1), (2, answers.part2)] {
+                        let check = expected.check(day, set, part, &answer);
+
+                        match check {
+                            Check::Pass => passed += 1,
+                            Check::Fail(_) => failed += 1,
+                            Check::Unknown => unknown += 1,
+                        }
+
+                        row.push(format!("{answer} {check}"));
+                    }
+                }
+                Err(e) => {
+                    failed += 2;
+                    row.extend([errors.add(day, set, e), String::new()]);
+                }
+            }
+
+            table.add_row(row);
+        }
+    }
+
+    table.print();
+    errors.print();
+
+    println!();
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+
+    Ok(if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Options for the bench command
+struct BenchOptions {
+    input: Option<String>,
+    runs: usize,
+    format: Format,
+}
+
+impl BenchOptions {
+    /// Parses bench options from the command line
+    fn parse(args: &[&str]) -> Result<Self, String> {
+        let mut options = Self {
+            input: None,
+            runs: 10,
+            format: Format::Json,
+        };
+
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            let mut value = || {
+                iter.next()
+                    .ok_or_else(|| format!("Missing value for {arg}"))
+            };
+
+            match *arg {
+                "--input" => options.input = Some(value()?.to_string()),
+                "--runs" => {
+                    options.runs = value()?
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|runs| *runs > 0)
+                        .ok_or_else(|| format!("Invalid number of runs for {arg}"))?
+                }
+                "--format" => options.format = value()?.parse()?,
+                _ => return Err(format!("Invalid option '{arg}'\n\n{USAGE}")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Benchmarks the solutions for a range of days, printing median times and writing the results
+fn bench(days: RangeInclusive<usize>, options: &BenchOptions) -> ExitCode {
+    let mut table = Table::new(&["Day", "Load", "Parse", "Part 1", "Part 2", "Total"]);
+    let mut errors = Errors::default();
+    let mut failed = false;
+
+    let set = options.input.as_deref();
+    let resolver = resolver(set);
+
+    for day in days {
+        let row = match (DAYS[day - 1].bench)(&resolver, options.runs) {
+            Ok((answers, stats)) => {
+                let result = DayBench {
+                    day,
+                    set: options.input.clone(),
+                    runs: options.runs,
+                    answers,
+                    stats,
+                };
+
+                if let Err(e) = result.write(options.format) {
+                    eprintln!("Failed to write results for day {day}: {e}");
+                    failed = true;
+                }
+
+                let mut row = vec![day.to_string()];
+
+                row.extend(
+                    stats
+                        .phases()
+                        .iter()
+                        .map(|(_, stats)| format!("{:.3?}", stats.median)),
+                );
+
+                row.push(format!("{:.3?}", stats.total_median()));
+
+                row
+            }
+            Err(e) => {
+                let mut row = vec![day.to_string(), errors.add(day, set, e)];
+                row.resize(6, String::new());
+
+                row
+            }
+        };
+
+        table.add_row(row);
+    }
+
+    table.print();
+    errors.print();
+
+    if failed || !errors.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Options for the fetch command
+struct FetchOptions {
+    year: u16,
+    user: Option<String>,
+    token: Option<String>,
+}
+
+impl FetchOptions {
+    /// Parses fetch options from the command line
+    fn parse(args: &[&str]) -> Result<Self, String> {
+        let mut options = Self {
+            year: DEFAULT_YEAR,
+            user: None,
+            token: None,
+        };
+
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            let value = iter
+                .next()
+                .ok_or_else(|| format!("Missing value for {arg}"))?;
+
+            match *arg {
+                "--year" => {
+                    options.year = value
+                        .parse()
+                        .ok()
+                        .filter(|&year| year >= FIRST_YEAR)
+                        .ok_or_else(|| format!("Invalid year for {arg}"))?
+                }
+                "--user" => options.user = Some(value.to_string()),
+                "--token" => options.token = Some(value.to_string()),
+                _ => return Err(format!("Invalid option '{arg}'\n\n{USAGE}")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Downloads the inputs for a range of days which are not already cached
+fn fetch(days: RangeInclusive<usize>, options: &FetchOptions) -> Result<ExitCode, String> {
+    let token = match (&options.token, &options.user) {
+        (Some(token), _) => token.clone(),
+        (None, Some(user)) => format!("token-{user}.txt"),
+        (None, None) => "token.txt".to_string(),
+    };
+
+    let fetcher = CurlFetcher::from_token_file(&token).map_err(|e| e.to_string())?;
+
+    let mut resolver = Resolver::new().year(options.year).fetcher(fetcher);
+
+    if let Some(user) = &options.user {
+        resolver = resolver.user(user);
+    }
+
+    let mut failed = false;
+
+    for day in days {
+        match resolver.resolve(day) {
+            Ok(path) => println!("Day {day}: {}", path.display()),
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+/// Extracts the example inputs from a saved puzzle page in to the inputs directory
+fn examples(day: usize, page: &str) -> Result<ExitCode, String> {
+    let html = fs::read_to_string(page).map_err(|e| format!("Unable to read {page}: {e}"))?;
+
+    let files = aoc::examples::write_examples(day, &html, Path::new("inputs"))
+        .map_err(|e| format!("Unable to write examples: {e}"))?;
+
+    if files.is_empty() {
+        return Err(format!("No <pre><code> blocks found in {page}"));
+    }
+
+    for (path, written) in files {
+        if written {
+            println!("Wrote {path}");
+        } else {
+            println!("{path} already exists, skipped");
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Creates the crates for a new day in the current workspace
+fn new_day(day: usize, vis: bool) -> Result<ExitCode, String> {
+    let changes = new::new_day(Path::new("."), day, vis).map_err(|e| e.to_string())?;
+
+    for change in changes {
+        println!("{change}");
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Errors returned by solutions, printed in full after the results table
+#[derive(Default)]
+struct Errors(Vec<(String, String)>);
+
+impl Errors {
+    /// Records an error for a day and input set, returning a single line summary for the table
+    fn add(&mut self, day: usize, set: Option<&str>, e: Box<dyn Error>) -> String {
+        let message = e.to_string();
+
+        let summary = if message.contains('\n') {
+            "Error (see below)".to_string()
+        } else {
+            format!("Error: {message}")
+        };
+
+        let label = match set {
+            Some(name) => format!("Day {day} ({name})"),
+            None => format!("Day {day}"),
+        };
+
+        self.0.push((label, message));
+
+        summary
+    }
+
+    /// Returns true if no errors have been recorded
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Prints multi-line error reports to stderr
+    fn print(&self) {
+        for (label, message) in &self.0 {
+            if message.contains('\n') {
+                eprintln!("\n{label}:\n{message}");
+            }
+        }
+    }
+}
+
+/// Parses a single day number
+fn parse_day(arg: &str) -> Result<usize, String> {
+    let days = parse_days(arg)?;
+
+    if days.start() == days.end() {
+        Ok(*days.start())
+    } else {
+        Err(format!("Expected a single day, got '{arg}'"))
+    }
+}
+
+/// Parses the number of a day which may not be in the runner yet
+fn parse_new_day(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day number '{arg}', expected 1 to 25")),
+    }
+}
+
+/// Parses a day selection (N, A..B, A..=B or all) to a range of days
+fn parse_days(arg: &str) -> Result<RangeInclusive<usize>, String> {
+    let parse_day = |s: &str| {
+        s.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid day number '{s}'"))
+    };
+
+    let range = if arg == "all" {
+        1..=DAYS.len()
+    } else if let Some((start, end)) = arg.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = arg.split_once("..") {
+        parse_day(start)?..=parse_day(end)?.saturating_sub(1)
+    } else {
+        let day = parse_day(arg)?;
+        day..=day
+    };
+
+    if range.is_empty() || *range.start() < 1 || *range.end() > DAYS.len() {
+        Err(format!("Days must be in the range 1 to {}", DAYS.len()))
+    } else {
+        Ok(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(5..=5));
+        assert_eq!(parse_days("all"), Ok(1..=25));
+        assert_eq!(parse_days("3..=9"), Ok(3..=9));
+        assert_eq!(parse_days("3..9"), Ok(3..=8));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("9..3").is_err());
+        assert!(parse_days("1..1").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_fetch_options() {
+        let options = FetchOptions::parse(&["--year", "2015", "--user", "alice"]).unwrap();
+        assert_eq!(options.year, 2015);
+        assert_eq!(options.user.as_deref(), Some("alice"));
+
+        assert_eq!(FetchOptions::parse(&[]).unwrap().year, DEFAULT_YEAR);
+
+        for year in ["1900", "2014", "x"] {
+            assert_eq!(
+                FetchOptions::parse(&["--year", year]).err().unwrap(),
+                "Invalid year for --year"
+            );
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Template directory for a day's solution crate
+const DAY_TEMPLATE: &str = "template";
+
+/// Template directory for a day's visualisation crate
+const VIS_TEMPLATE: &str = "templatevis";
+
+/// Runner manifest, which depends on each day's crate
+const RUNNER_MANIFEST: &str = "runner/Cargo.toml";
+
+/// Runner module listing each day's entry points
+const RUNNER_DAYS: &str = "runner/src/days.rs";
+
+/// Creates the crates for a new day from the templates, adds them to the workspace members and
+/// adds the day to the runner. Nothing is written if any of the crates already exist, and no
+/// partial crate is left behind if creating them fails. Returns a description of each change
+pub fn new_day(root: &Path, day: usize, vis: bool) -> Result<Vec<String>, Box<dyn Error>> {
+    let daypad = format!("{day:02}");
+
+    let mut crates = vec![(DAY_TEMPLATE, format!("day{daypad}"))];
+
+    if vis {
+        crates.push((VIS_TEMPLATE, format!("day{daypad}vis")));
+    }
+
+    // Refuse to overwrite existing code
+    for (_, dir) in &crates {
+        if root.join(dir).exists() {
+            Err(format!("{dir} already exists"))?;
+        }
+    }
+
+    // Edit the manifests and day list before creating anything so a bad file leaves no trace
+    let names = crates
+        .iter()
+        .map(|(_, dir)| dir.as_str())
+        .collect::<Vec<_>>();
+
+    let edit = |file: &str,
+                edit: &dyn Fn(&str) -> Result<String, String>|
+     -> Result<(PathBuf, String, String), Box<dyn Error>> {
+        let path = root.join(file);
+        let original = fs::read_to_string(&path)?;
+        let edited = edit(&original)?;
+
+        Ok((path, original, edited))
+    };
+
+    let edits = [
+        edit("Cargo.toml", &|text| add_members(text, &names))?,
+        edit(RUNNER_MANIFEST, &|text| add_dependency(text, &crates[0].1))?,
+        edit(RUNNER_DAYS, &|text| add_day(text, day))?,
+    ];
+
+    // Build each crate in a staging directory so a failed copy leaves no partial crate
+    let staged = crates
+        .iter()
+        .map(|(_, dir)| root.join(format!(".{dir}.new")))
+        .collect::<Vec<_>>();
+
+    let built = crates
+        .iter()
+        .zip(&staged)
+        .try_for_each(|((template, dir), stage)| {
+            copy_template(&root.join(template), stage, &|text| {
+                substitute(text, day, dir)
+            })
+        });
+
+    if let Err(e) = built {
+        remove_dirs(&staged);
+        return Err(e);
+    }
+
+    // Move the crates in to place then add them to the workspace and runner
+    let mut created = Vec::new();
+
+    for ((_, dir), stage) in crates.iter().zip(&staged) {
+        let target = root.join(dir);
+
+        if let Err(e) = fs::rename(stage, &target) {
+            remove_dirs(&staged);
+            remove_dirs(&created);
+            return Err(e.into());
+        }
+
+        created.push(target);
+    }
+
+    if let Err(e) = write_edits(&edits) {
+        remove_dirs(&created);
+        return Err(e);
+    }
+
+    let mut changes = crates
+        .iter()
+        .map(|(template, dir)| format!("Created {dir} from {template}"))
+        .collect::<Vec<_>>();
+
+    changes.push(format!("Added {} to workspace members", names.join(", ")));
+    changes.push(format!(
+        "Added {} to {RUNNER_MANIFEST} and {RUNNER_DAYS}",
+        crates[0].1
+    ));
+
+    // Create the example answers file for the test stub
+    let answers = root.join("inputs").join(format!("example{daypad}.toml"));
+
+    if !answers.exists() {
+        fs::write(
+            &answers,
+            format!(
+                "\
+# Expected answers for the day {day} examples
+
+# [1]
+# part1 = 0
+# part2 = 0
+"
+            ),
+        )?;
+
+        changes.push(format!("Created {}", answers.display()));
+    }
+
+    Ok(changes)
+}
+
+/// Replaces the template variables $daypad, $dir and $day
+fn substitute(text: &str, day: usize, dir: &str) -> String {
+    text.replace("$daypad", &format!("{day:02}"))
+        .replace("$dir", dir)
+        .replace("$day", &day.to_string())
+}
+
+/// Writes edited files, each with its original and edited text. If a write fails the files
+/// already written are restored
+fn write_edits(edits: &[(PathBuf, String, String)]) -> Result<(), Box<dyn Error>> {
+    for (i, (path, _, edited)) in edits.iter().enumerate() {
+        if let Err(e) = fs::write(path, edited) {
+            for (path, original, _) in &edits[..i] {
+                let _ = fs::write(path, original);
+            }
+
+            return Err(e.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes directories, ignoring errors as this is only used to clean up after a failure
+fn remove_dirs(dirs: &[PathBuf]) {
+    for dir in dirs {
+        let _ = fs::remove_dir_all(dir);
+    }
+}
+
+/// Recursively copies a template directory, substituting variables in each file
+fn copy_template(
+    from: &Path,
+    to: &Path,
+    subst: &dyn Fn(&str) -> String,
+) -> Result<(), Box<dyn Error>> {
+    fs::create_dir(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, subst)?;
+        } else {
+            fs::write(target, subst(&fs::read_to_string(entry.path())?))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds crates to the workspace members list in a manifest, keeping the list sorted
+fn add_members(manifest: &str, names: &[&str]) -> Result<String, String> {
+    const START: &str = "members = [";
+
+    let start = manifest
+        .find(START)
+        .ok_or("Workspace members not found in Cargo.toml")?
+        + START.len();
+
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Workspace members list not terminated in Cargo.toml")?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .chain(names.iter().copied())
+        .collect::<Vec<_>>();
+
+    members.sort();
+    members.dedup();
+
+    let list = members
+        .iter()
+        .map(|m| format!("    \"{m}\",\n"))
+        .collect::<String>();
+
+    Ok(format!(
+        "{}\n{list}{}",
+        &manifest[..start],
+        &manifest[end..]
+    ))
+}
+
+/// Adds a path dependency on a day's crate to the runner manifest, after the other days
+fn add_dependency(manifest: &str, dir: &str) -> Result<String, String> {
+    const START: &str = "[dependencies]\n";
+
+    let start = manifest
+        .find(START)
+        .ok_or_else(|| format!("Dependencies not found in {RUNNER_MANIFEST}"))?
+        + START.len();
+
+    // Dependencies run to the next blank line or table
+    let end = start
+        + manifest[start..]
+            .find("\n\n")
+            .or_else(|| manifest[start..].find("\n["))
+            .map_or(manifest.len() - start, |end| end + 1);
+
+    let line = format!("{dir} = {{ path = \"../{dir}\" }}\n");
+
+    // Insert after the last day which sorts before the new one, otherwise at the end
+    let mut insert = end;
+    let mut pos = start;
+
+    for dep in manifest[start..end].split_inclusive('\n') {
+        if dep.starts_with(&format!("{dir} ")) {
+            Err(format!(
+                "{dir} is already a dependency in {RUNNER_MANIFEST}"
+            ))?;
+        }
+
+        if dep.starts_with("day") {
+            if *dep > *line {
+                insert = pos;
+                break;
+            }
+
+            insert = pos + dep.len();
+        }
+
+        pos += dep.len();
+    }
+
+    Ok(format!(
+        "{}{line}{}",
+        &manifest[..insert],
+        &manifest[insert..]
+    ))
+}
+
+/// Adds the entry points for a day to the end of the runner's day list. Days are indexed by
+/// number, so every earlier day must already be in the list
+fn add_day(days: &str, day: usize) -> Result<String, String> {
+    const START: &str = "pub const DAYS: &[Day] = &[";
+
+    let start = days
+        .find(START)
+        .ok_or_else(|| format!("Day list not found in {RUNNER_DAYS}"))?
+        + START.len();
+
+    let end = start
+        + days[start..]
+            .find("];")
+            .ok_or_else(|| format!("Day list not terminated in {RUNNER_DAYS}"))?;
+
+    let count = days[start..end].matches("day!(").count();
+
+    if count >= day {
+        Err(format!("Day {day} is already in {RUNNER_DAYS}"))?;
+    } else if count + 1 < day {
+        Err(format!(
+            "Days before day {day} must be added to {RUNNER_DAYS} first"
+        ))?;
+    }
+
+    Ok(format!(
+        "{}    day!(day{day:02}::Day{day:02}),\n{}",
+        &days[..end],
+        &days[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_members() {
+        let manifest = "\
+[workspace]
+members = [
+    \"aoc\",
+    \"day01\",
+    \"day03\",
+    \"runner\",
+]
+
+[profile.release]
+lto = true
+";
+
+        assert_eq!(
+            add_members(manifest, &["day02", "day02vis"]).unwrap(),
+            "\
+[workspace]
+members = [
+    \"aoc\",
+    \"day01\",
+    \"day02\",
+    \"day02vis\",
+    \"day03\",
+    \"runner\",
+]
+
+[profile.release]
+lto = true
+"
+        );
+
+        assert!(add_members("[workspace]\n", &["day02"]).is_err());
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "\
+[dependencies]
+aoc = { path = \"../aoc\" }
+day01 = { path = \"../day01\" }
+day03 = { path = \"../day03\" }
+
+[dev-dependencies]
+serde_json = \"1.0\"
+";
+
+        assert_eq!(
+            add_dependency(manifest, "day02").unwrap(),
+            "\
+[dependencies]
+aoc = { path = \"../aoc\" }
+day01 = { path = \"../day01\" }
+day02 = { path = \"../day02\" }
+day03 = { path = \"../day03\" }
+
+[dev-dependencies]
+serde_json = \"1.0\"
+"
+        );
+
+        assert_eq!(
+            add_dependency(manifest, "day04").unwrap(),
+            "\
+[dependencies]
+aoc = { path = \"../aoc\" }
+day01 = { path = \"../day01\" }
+day03 = { path = \"../day03\" }
+day04 = { path = \"../day04\" }
+
+[dev-dependencies]
+serde_json = \"1.0\"
+"
+        );
+
+        // First day goes at the end of the dependencies
+        assert_eq!(
+            add_dependency("[dependencies]\naoc = { path = \"../aoc\" }\n", "day01").unwrap(),
+            "[dependencies]\naoc = { path = \"../aoc\" }\nday01 = { path = \"../day01\" }\n"
+        );
+
+        assert_eq!(
+            add_dependency(manifest, "day03").unwrap_err(),
+            "day03 is already a dependency in runner/Cargo.toml"
+        );
+        assert!(add_dependency("[package]\n", "day01").is_err());
+    }
+
+    #[test]
+    fn test_add_day() {
+        let days = "\
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+];
+";
+
+        assert_eq!(
+            add_day(days, 3).unwrap(),
+            "\
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+];
+"
+        );
+
+        assert_eq!(
+            add_day(days, 2).unwrap_err(),
+            "Day 2 is already in runner/src/days.rs"
+        );
+        assert_eq!(
+            add_day(days, 5).unwrap_err(),
+            "Days before day 5 must be added to runner/src/days.rs first"
+        );
+        assert!(add_day("pub const DAYS: [Day; 2] = [\n];\n", 3).is_err());
+    }
+
+    /// Creates a minimal workspace with the templates in a temporary directory
+    fn temp_workspace(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-new-{name}-{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&root);
+
+        for dir in ["inputs", "runner/src", "template/src", "templatevis/src"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"runner\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(RUNNER_MANIFEST),
+            "[dependencies]\naoc = { path = \"../aoc\" }\n",
+        )
+        .unwrap();
+        fs::write(root.join(RUNNER_DAYS), "pub const DAYS: &[Day] = &[\n];\n").unwrap();
+        fs::write(root.join("template/Cargo.toml"), "name = \"$dir\"\n").unwrap();
+        fs::write(root.join("template/src/lib.rs"), "// Day $day\n").unwrap();
+        fs::write(root.join("templatevis/Cargo.toml"), "name = \"$dir\"\n").unwrap();
+
+        root
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = temp_workspace("ok");
+
+        let changes = new_day(&root, 1, true).unwrap();
+
+        assert_eq!(
+            changes[..4],
+            [
+                "Created day01 from template",
+                "Created day01vis from templatevis",
+                "Added day01, day01vis to workspace members",
+                "Added day01 to runner/Cargo.toml and runner/src/days.rs",
+            ]
+        );
+
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day01vis\",\n    \"runner\",\n]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(RUNNER_MANIFEST)).unwrap(),
+            "[dependencies]\naoc = { path = \"../aoc\" }\nday01 = { path = \"../day01\" }\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(RUNNER_DAYS)).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    day!(day01::Day01),\n];\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("day01/Cargo.toml")).unwrap(),
+            "name = \"day01\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("day01/src/lib.rs")).unwrap(),
+            "// Day 1\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("day01vis/Cargo.toml")).unwrap(),
+            "name = \"day01vis\"\n"
+        );
+        assert!(root.join("inputs/example01.toml").exists());
+
+        // The next day is added after it
+        new_day(&root, 2, false).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join(RUNNER_DAYS)).unwrap(),
+            "pub const DAYS: &[Day] = &[\n    day!(day01::Day01),\n    day!(day02::Day02),\n];\n"
+        );
+
+        // A second attempt must not touch anything
+        assert_eq!(
+            new_day(&root, 1, false).unwrap_err().to_string(),
+            "day01 already exists"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_failure() {
+        let root = temp_workspace("fail");
+        let files = ["Cargo.toml", RUNNER_MANIFEST, RUNNER_DAYS];
+        let originals = files.map(|file| fs::read_to_string(root.join(file)).unwrap());
+
+        // Earlier days are missing from the runner
+        assert_eq!(
+            new_day(&root, 7, false).unwrap_err().to_string(),
+            "Days before day 7 must be added to runner/src/days.rs first"
+        );
+
+        // A template file which isn't UTF-8 fails the copy part way through
+        fs::write(root.join("templatevis/src/main.rs"), [0xff, 0xfe]).unwrap();
+
+        assert!(new_day(&root, 1, true).is_err());
+
+        for (file, original) in files.iter().zip(&originals) {
+            assert_eq!(&fs::read_to_string(root.join(file)).unwrap(), original);
+        }
+
+        let mut entries = fs::read_dir(&root)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        entries.sort();
+
+        assert_eq!(
+            entries,
+            ["Cargo.toml", "inputs", "runner", "template", "templatevis"]
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_substitute() {
+        assert_eq!(
+            substitute("name = \"$dir\" // Day $day, Day$daypad", 7, "day07vis"),
+            "name = \"day07vis\" // Day 7, Day07"
+        );
+    }
+}
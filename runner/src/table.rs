@@ -0,0 +1,67 @@
+use std::cmp::max;
+
+/// Text table with a header row
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Creates a new table with the given column headers
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a row to the table
+    pub fn add_row(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.headers.len());
+
+        self.rows.push(row);
+    }
+
+    /// Prints the table to stdout
+    pub fn print(&self) {
+        // Calculate column widths
+        let widths = self.rows.iter().fold(
+            self.headers.iter().map(|h| h.len()).collect::<Vec<_>>(),
+            |widths, row| {
+                widths
+                    .iter()
+                    .zip(row)
+                    .map(|(w, cell)| max(*w, cell.len()))
+                    .collect()
+            },
+        );
+
+        let print_row = |row: &[String]| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, w)| format!("{cell:w$}"))
+                .collect::<Vec<_>>()
+                .join(" | ");
+
+            println!("{}", line.trim_end());
+        };
+
+        // Print header and separator
+        print_row(&self.headers);
+
+        println!(
+            "{}",
+            widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("-+-")
+        );
+
+        // Print rows
+        for row in &self.rows {
+            print_row(row);
+        }
+    }
+}
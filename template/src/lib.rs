@@ -0,0 +1,49 @@
+use std::{error::Error, fmt::Display};
+
+use aoc::{input::Input, solution::Solution};
+
+/// Day $day solution
+pub struct Day$daypad;
+
+impl Solution for Day$daypad {
+    const DAY: usize = $day;
+
+    type Input = Vec<InputEnt>;
+
+    fn parse(input: &Input) -> Result<Self::Input, Box<dyn Error>> {
+        input.parse_vec(input_transform)
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        part2(input)
+    }
+}
+
+fn part1(input: &[InputEnt]) -> u64 {
+    0 // TODO
+}
+
+fn part2(input: &[InputEnt]) -> u64 {
+    0 // TODO
+}
+
+// Input parsing
+
+type InputEnt = String; // TODO
+
+fn input_transform(line: String) -> InputEnt {
+    // TODO
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples are extracted with `aoc examples $day <page>`, answers go in inputs/example$daypad.toml
+    aoc::example_tests!(Day$daypad);
+}
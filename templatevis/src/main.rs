@@ -0,0 +1,55 @@
+use std::error::Error;
+
+use aoc::{gif::Format, input::parse_input_vec};
+
+/// Scale factor for each cell
+const SCALE: u16 = 4;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    // Get output format
+    let format = Format::from_args()?;
+
+    // Get input
+    let input = parse_input_vec($day, input_transform)?;
+
+    // Render
+    render(&input, "vis/day$daypad", format)?;
+
+    Ok(())
+}
+
+fn render(input: &[InputEnt], file: &str, format: Format) -> Result<(), Box<dyn Error>> {
+    // Create output
+    let palette = [[0, 0, 0], [255, 255, 255]];
+
+    let width = input.iter().map(|l| l.len()).max().unwrap_or(0) as u16;
+    let height = input.len() as u16;
+
+    let mut sink = format.create(file, &palette, width, height, SCALE, SCALE)?;
+
+    // Draw the input
+    let mut frame = sink.empty_frame();
+
+    for (y, line) in input.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c != '.' {
+                frame.set(x, y, 1); // TODO
+            }
+        }
+    }
+
+    sink.draw_frame(&frame, 100)?;
+
+    sink.finish()?;
+
+    Ok(())
+}
+
+// Input parsing
+
+type InputEnt = String; // TODO
+
+fn input_transform(line: String) -> InputEnt {
+    // TODO
+    line
+}